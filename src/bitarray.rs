@@ -0,0 +1,382 @@
+use duplicate::duplicate_item;
+use ndarray::{
+    Array,
+    Array1,
+    ArrayBase,
+    ArrayViewMut,
+    Axis,
+    Data,
+    Dimension,
+    IntoDimension,
+    Ix1,
+    Ix2,
+    NdProducer,
+    RemoveAxis,
+    Zip,
+};
+
+use super::boolarray::{
+    ArrayWithBoolMaskMethods,
+    BoolArray,
+};
+use super::generic::{
+    unravel_index,
+};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A boolean array packed into bits, taking an eighth of the memory of a `BoolArray`.
+///
+/// Elements are stored in row-major order, 64 to a `u64` word. Any bits past the last
+/// element are kept clear, so that whole words can be tested and counted at once.
+///
+/// Convert from and to `BoolArray`s with `BitArray::from` and `BoolArray::from`, or
+/// `from_bool_array` and `to_bool_array`. `ArrayWithBitIterMethods` and
+/// `ArrayWithBitMaskMethods` mirror `ArrayWithBoolIterMethods` and
+/// `ArrayWithBoolMaskMethods`, which are only implemented for `ndarray` types.
+///
+/// The other `BoolArray` traits, such as `ArrayWithBoolMaskSelectMethods`,
+/// `ArrayWithBoolParallelMaskMethods` and the logical operators, are not implemented
+/// for `BitArray`s; unpack them with `to_bool_array` first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitArray<D> {
+    words: Vec<u64>,
+    dim: D,
+}
+
+pub type BitArray1 = BitArray<Ix1>;
+pub type BitArray2 = BitArray<Ix2>;
+
+impl<D> BitArray<D>
+where   D: Dimension,
+{
+    /// Create a `BitArray` of the given shape, with every element set to `value`.
+    pub fn from_elem<Sh>(shape: Sh, value: bool) -> Self
+    where   Sh: IntoDimension<Dim = D>,
+    {
+        let dim = shape.into_dimension();
+        let len = dim.size();
+
+        let mut words = vec![if value { u64::MAX } else { 0 }; len.div_ceil(WORD_BITS)];
+
+        // Keep the bits past the last element clear.
+        let tail = len % WORD_BITS;
+
+        if let (Some(last), true) = (words.last_mut(), tail > 0) {
+            *last &= (1 << tail) - 1;
+        }
+
+        return Self { words, dim };
+    }
+
+    /// Pack any boolean array into bits, in its logical order.
+    pub fn from_bool_array<S>(array: &ArrayBase<S, D>) -> Self
+    where   S: Data<Elem = bool>,
+    {
+        let mut words = vec![0; array.len().div_ceil(WORD_BITS)];
+
+        array.iter()
+             .enumerate()
+             .filter(|(_, &value)| value)
+             .for_each(|(offset, _)| words[offset / WORD_BITS] |= 1 << (offset % WORD_BITS));
+
+        return Self { words, dim: array.raw_dim() };
+    }
+
+    /// Unpack into a `BoolArray` of the same shape.
+    pub fn to_bool_array(&self) -> BoolArray<D> {
+        return Array::from_shape_vec(self.raw_dim(), self.iter().collect()).unwrap();
+    }
+
+    pub fn raw_dim(&self) -> D {
+        return self.dim.clone();
+    }
+
+    pub fn shape(&self) -> &[usize] {
+        return self.dim.slice();
+    }
+
+    pub fn ndim(&self) -> usize {
+        return self.dim.ndim();
+    }
+
+    pub fn len(&self) -> usize {
+        return self.dim.size();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+
+    /// Row-major offset of `index`, or `None` if it is out of bounds or has the wrong
+    /// number of dimensions.
+    fn offset_of<I>(&self, index: I) -> Option<usize>
+    where   I: IntoDimension<Dim = D>,
+    {
+        let index = index.into_dimension();
+
+        if index.ndim() != self.ndim() {
+            return None;
+        }
+
+        return index.slice()
+                    .iter()
+                    .zip(self.shape())
+                    .try_fold(
+                        0,
+                        | offset, (&i, &len) | if i < len { Some(offset * len + i) } else { None }
+                    );
+    }
+
+    /// The element at `index`, or `None` if it is out of bounds.
+    pub fn get<I>(&self, index: I) -> Option<bool>
+    where   I: IntoDimension<Dim = D>,
+    {
+        return self.offset_of(index)
+                   .map(|offset| self.words[offset / WORD_BITS] >> (offset % WORD_BITS) & 1 == 1);
+    }
+
+    /// Set the element at `index` to `value`.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set<I>(&mut self, index: I, value: bool)
+    where   I: IntoDimension<Dim = D>,
+    {
+        let offset = self.offset_of(index).expect("index out of bounds.");
+        let word = &mut self.words[offset / WORD_BITS];
+
+        if value {
+            *word |= 1 << (offset % WORD_BITS);
+        } else {
+            *word &= !(1 << (offset % WORD_BITS));
+        }
+    }
+
+    /// Iterate over the elements in logical order.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        return (0..self.len()).map(
+            | offset | self.words[offset / WORD_BITS] >> (offset % WORD_BITS) & 1 == 1
+        );
+    }
+
+    /// Row-major offsets of the `true` elements, in ascending order.
+    fn set_bits(&self) -> impl Iterator<Item = usize> + '_ {
+        return self.words
+                   .iter()
+                   .enumerate()
+                   .flat_map(
+                       | (index, &word) | {
+                           let mut word = word;
+
+                           std::iter::from_fn(
+                               move || {
+                                   if word == 0 {
+                                       return None;
+                                   }
+
+                                   let bit = word.trailing_zeros() as usize;
+                                   word &= word - 1;
+
+                                   Some(index * WORD_BITS + bit)
+                               }
+                           )
+                       }
+                   );
+    }
+}
+
+impl<S, D> From<&ArrayBase<S, D>> for BitArray<D>
+where   S: Data<Elem = bool>,
+        D: Dimension,
+{
+    fn from(array: &ArrayBase<S, D>) -> Self {
+        return Self::from_bool_array(array);
+    }
+}
+
+impl<D> From<&BitArray<D>> for BoolArray<D>
+where   D: Dimension,
+{
+    fn from(array: &BitArray<D>) -> Self {
+        return array.to_bool_array();
+    }
+}
+
+/// Reductions and searches over `BitArray`s, as `ArrayWithBoolIterMethods` does for
+/// `BoolArray`s.
+pub trait ArrayWithBitIterMethods<D>
+where
+    D: Dimension
+{
+    /// Returns true if any of the elements are true.
+    fn any(&self) -> bool;
+
+    /// Returns false if any of the elements are false.
+    fn all(&self) -> bool;
+
+    /// Count all the `true`s in an array
+    fn count(&self) -> usize;
+
+    /// Indices of `true` values in the array, in logical order.
+    fn indices(&self) -> Array1<D::Pattern>;
+
+    /// `any` for each lane along `axis`.
+    fn any_axis(&self, axis: Axis) -> BoolArray<D::Smaller>
+    where   D: RemoveAxis;
+
+    /// `all` for each lane along `axis`.
+    fn all_axis(&self, axis: Axis) -> BoolArray<D::Smaller>
+    where   D: RemoveAxis;
+
+    /// `count` for each lane along `axis`.
+    fn count_axis(&self, axis: Axis) -> Array<usize, D::Smaller>
+    where   D: RemoveAxis;
+}
+
+impl<D> ArrayWithBitIterMethods<D> for BitArray<D>
+where   D: Dimension {
+    /// Returns true if any of the elements are true, testing whole words at once.
+    fn any(&self) -> bool {
+        return self.words.iter().any(|&word| word != 0);
+    }
+
+    /// Returns false if any of the elements are false.
+    fn all(&self) -> bool {
+        return self.count() == self.len();
+    }
+
+    /// Count all the `true`s in an array, by popcount.
+    fn count(&self) -> usize {
+        return self.words.iter().map(|word| word.count_ones() as usize).sum();
+    }
+
+    /// Indices of `true` values in the array, in logical order.
+    fn indices(&self) -> Array1<D::Pattern> {
+        return self.set_bits()
+                   .map(|offset| unravel_index(offset, &self.dim).into_pattern())
+                   .collect();
+    }
+
+    /// Returns true for each lane along `axis` if any of its elements are true.
+    fn any_axis(&self, axis: Axis) -> BoolArray<D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.count_axis(axis).mapv(|count| count > 0);
+    }
+
+    /// Returns false for each lane along `axis` if any of its elements are false.
+    fn all_axis(&self, axis: Axis) -> BoolArray<D::Smaller>
+    where   D: RemoveAxis
+    {
+        let len = self.dim[axis.index()];
+
+        return self.count_axis(axis).mapv(|count| count == len);
+    }
+
+    /// Count all the `true`s in each lane along `axis`.
+    fn count_axis(&self, axis: Axis) -> Array<usize, D::Smaller>
+    where   D: RemoveAxis
+    {
+        // In row-major order, the offset of an element splits into its position before
+        // `axis`, along `axis` and after `axis`; the result drops the middle one.
+        let len = self.dim[axis.index()];
+        let inner: usize = self.shape()[axis.index() + 1..].iter().product();
+
+        let mut counts = Array::zeros(self.dim.remove_axis(axis));
+        let counts_slice = counts.as_slice_mut().unwrap();
+
+        self.set_bits().for_each(
+            | offset | counts_slice[offset / (len * inner) * inner + offset % inner] += 1
+        );
+
+        return counts;
+    }
+}
+
+// =====================================================================================
+
+/// Using `BitArray`s as masks for identical sized arrays, as
+/// `ArrayWithBoolMaskMethods` does for `BoolArray`s.
+///
+/// `T` can be a `&mut Array` or a `&mut ArrayViewMut`, with `F` any closure taking
+/// `&mut A`; or a `Zip` of up to 5 producers, with `F` any closure taking their items.
+pub trait ArrayWithBitMaskMethods<D, A, F, T>: ArrayWithBitIterMethods<D>
+where
+    D: Dimension
+{
+    fn mask_apply_inplace(
+        &self,
+        array: T,
+        f: F,
+    );
+}
+
+// Cannot use &mut ArcArray: it will break sharing
+// See https://docs.rs/ndarray/latest/ndarray/type.ArcArray.html
+#[duplicate_item(
+    __rhs_type__                        __impl_generics__;
+    [ &mut Array<A, D> ]                [ D, A, F ];
+    [ &mut ArrayViewMut<'b, A, D> ]     [ 'b, D, A, F ];
+)]
+impl<__impl_generics__> ArrayWithBitMaskMethods<D, A, F, __rhs_type__>
+for BitArray<D>
+where   D: Dimension,
+        F: FnMut(&mut A),
+{
+    /// Apply a function to another mutable array, using itself as a mask.
+    ///
+    /// The function is only applied to ``array[S<D>]`` if `self[S<D>]` is ``true``.
+    ///
+    /// Panics if `array` is not of the same shape as `self`.
+    fn mask_apply_inplace(
+        &self,
+        array: __rhs_type__,
+        mut f: F,
+    )
+    {
+        assert_eq!(
+            array.shape(), self.shape(),
+            "mask must be of the same shape as array."
+        );
+
+        array.iter_mut()
+             .zip(self.iter())
+             .filter(|(_, mask)| *mask)
+             .for_each(|(value, _)| f(value));
+    }
+}
+
+macro_rules! map_impl {
+    ($([$($p:ident)*],)+) => {
+        $(
+            #[allow(non_snake_case)]
+            impl<D, F, $($p),*> ArrayWithBitMaskMethods<D, ($($p::Item,)*), F, Zip<($($p,)*), D>>
+            for BitArray<D>
+            where   D: Dimension,
+                    F: FnMut($($p::Item),*),
+                    $($p: NdProducer<Dim=D> ,)*
+            {
+                /// Apply a function to the items of a `Zip`, using itself as a mask.
+                ///
+                /// A `Zip` visits its items in whichever order suits their memory
+                /// layout, so the mask is unpacked into a `BoolArray` once and zipped
+                /// along with them.
+                fn mask_apply_inplace(
+                    &self,
+                    array: Zip<($($p,)*), D>,
+                    f: F,
+                ) {
+                    self.to_bool_array().mask_apply_inplace(array, f);
+                }
+            }
+        )+
+    }
+}
+
+map_impl! {
+    [P1],
+    [P1 P2],
+    [P1 P2 P3],
+    [P1 P2 P3 P4],
+    [P1 P2 P3 P4 P5],
+}
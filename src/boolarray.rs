@@ -3,17 +3,31 @@ use ndarray::{
     ArcArray,
     Array,
     Array1,
+    ArrayBase,
     ArrayView,
     ArrayViewMut,
+    Axis,
+    Data,
+    DataMut,
     Dimension,
     Ix1,
     Ix2,
-    // NdProducer,
+    IxDyn,
+    NdProducer,
+    RemoveAxis,
     Zip,
+    aview0,
 };
+use ndarray::parallel::prelude::*;
 
 use super::generic::{
     ArrayProxiedMethods,
+    broadcast_onto,
+    broadcast_pair,
+    unravel_index,
+};
+use super::parallel::{
+    use_parallel,
 };
 
 pub type BoolArray<D> = Array<bool, D>;
@@ -52,6 +66,27 @@ where
     /// Equivalent to `where` in numpy, which is a reserved token in
     /// Rust.
     fn indices(&self) -> Array1<Self::Pattern>;
+
+    /// Returns true for each lane along `axis` if any of its elements are true.
+    fn any_axis(&self, axis: Axis) -> BoolArray<D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.view().map_axis(axis, |lane| lane.iter().any(|&v| v));
+    }
+
+    /// Returns false for each lane along `axis` if any of its elements are false.
+    fn all_axis(&self, axis: Axis) -> BoolArray<D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.view().map_axis(axis, |lane| lane.iter().all(|&v| v));
+    }
+
+    /// Count all the `true`s in each lane along `axis`.
+    fn count_axis(&self, axis: Axis) -> Array<usize, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.view().map_axis(axis, |lane| lane.iter().filter(|&v| *v).count());
+    }
 }
 
 
@@ -120,7 +155,209 @@ where   D: Dimension {
 
 // =====================================================================================
 
+/// Rayon-parallel counterparts of `ArrayWithBoolIterMethods`, prefixed with `par_`.
+///
+/// Arrays with fewer elements than `parallel_threshold()` are processed serially
+/// instead, as the overhead of parallelism would outweigh its gain.
+pub trait ArrayWithBoolParallelMethods<D>: ArrayWithBoolIterMethods<D>
+where
+    D: Dimension
+{
+    fn par_any(&self) -> bool;
+    fn par_all(&self) -> bool;
+    fn par_count(&self) -> usize;
+
+    /// Indices of `true` values in the array, in the same logical order as
+    /// `indices`.
+    fn par_indices(&self) -> Array1<Self::Pattern>
+    where   Self::Pattern: Send;
+}
+
+#[duplicate_item(
+    __array_type__                       __impl_generics__;
+    [ BoolArray<D> ]                     [ D ];
+    [ BoolArcArray<D> ]                  [ D ];
+    [ BoolArrayView<'a, D> ]             [ 'a, D ];
+    [ BoolArrayViewMut<'a, D> ]          [ 'a, D ];
+)]
+impl<__impl_generics__> ArrayWithBoolParallelMethods<D>
+for __array_type__
+where   D: Dimension {
+    fn par_any(&self) -> bool {
+        if !use_parallel(self.len()) {
+            return self.any();
+        }
+
+        return self.view().into_par_iter().any(|&v| v);
+    }
+
+    fn par_all(&self) -> bool {
+        if !use_parallel(self.len()) {
+            return self.all();
+        }
+
+        return self.view().into_par_iter().all(|&v| v);
+    }
+
+    fn par_count(&self) -> usize {
+        if !use_parallel(self.len()) {
+            return self.count();
+        }
+
+        return self.view().into_par_iter().filter(|&&v| v).count();
+    }
+
+    fn par_indices(&self) -> Array1<Self::Pattern>
+    where   Self::Pattern: Send
+    {
+        if !use_parallel(self.len()) {
+            return self.indices();
+        }
+
+        // Work on a row-major copy if need be, so that the order of the slice is the
+        // logical order of the array, and unravel the offsets of the `true`s into
+        // indices.
+        let standard = self.as_standard_layout();
+        let shape = self.raw_dim();
+
+        let indices: Vec<Self::Pattern> = {
+            standard
+            .as_slice()
+            .unwrap()
+            .par_iter()
+            .enumerate()
+            .filter(|(_, &value)| value)
+            .map(|(offset, _)| unravel_index(offset, &shape).into_pattern())
+            .collect()
+        };
+
+        return Array1::from(indices);
+    }
+}
+
+// =====================================================================================
+
+/// Elementwise logical operations between BoolArrays.
+///
+/// `other` can be of any array type; it is broadcast against `self` following the
+/// numpy rules, so it can have fewer dimensions than `self`, but not more. The
+/// resultant array is of the broadcast shape.
+///
+/// Panics if the shapes cannot be broadcast together.
+pub trait ArrayWithBoolLogicalMethods<D>: ArrayWithBoolIterMethods<D>
+where
+    D: Dimension
+{
+    #[duplicate_item(
+        __func_name__;
+        [ and ];
+        [ or ];
+        [ xor ];
+        [ and_not ];
+    )]
+    fn __func_name__<S, E>(&self, other: &ArrayBase<S, E>) -> BoolArray<D>
+    where   S: Data<Elem = bool>,
+            E: Dimension;
+
+    fn not(&self) -> BoolArray<D>;
+}
+
+#[duplicate_item(
+    __array_type__                       __impl_generics__;
+    [ BoolArray<D> ]                     [ D ];
+    [ BoolArcArray<D> ]                  [ D ];
+    [ BoolArrayView<'a, D> ]             [ 'a, D ];
+    [ BoolArrayViewMut<'a, D> ]          [ 'a, D ];
+)]
+impl<__impl_generics__> ArrayWithBoolLogicalMethods<D>
+for __array_type__
+where   D: Dimension {
+    #[duplicate_item(
+        __func_name__       __op__;
+        [ and ]             [ *lhs && *rhs ];
+        [ or ]              [ *lhs || *rhs ];
+        [ xor ]             [ *lhs ^ *rhs ];
+        [ and_not ]         [ *lhs && !*rhs ];
+    )]
+    fn __func_name__<S, E>(&self, other: &ArrayBase<S, E>) -> BoolArray<D>
+    where   S: Data<Elem = bool>,
+            E: Dimension,
+    {
+        let (lhs, rhs) = broadcast_pair(self, other);
+
+        return Zip::from(&lhs)
+                   .and(&rhs)
+                   .map_collect(|lhs, rhs| __op__);
+    }
+
+    fn not(&self) -> BoolArray<D> {
+        return self.mapv(|value| !value);
+    }
+}
+
+/// In-place counterparts of `ArrayWithBoolLogicalMethods`, like `&=` and `|=`.
+///
+/// `other` is broadcast onto the shape of `self`, which cannot change.
+///
+/// Panics if `other` cannot be broadcast onto `self`.
+pub trait ArrayWithBoolLogicalInplaceMethods<D>: ArrayWithBoolLogicalMethods<D>
+where
+    D: Dimension
+{
+    #[duplicate_item(
+        __func_name__;
+        [ and_inplace ];
+        [ or_inplace ];
+        [ xor_inplace ];
+        [ and_not_inplace ];
+    )]
+    fn __func_name__<S, E>(&mut self, other: &ArrayBase<S, E>)
+    where   S: Data<Elem = bool>,
+            E: Dimension;
+
+    fn not_inplace(&mut self);
+}
+
+#[duplicate_item(
+    __array_type__                       __impl_generics__;
+    [ BoolArray<D> ]                     [ D ];
+    [ BoolArcArray<D> ]                  [ D ];
+    [ BoolArrayViewMut<'a, D> ]          [ 'a, D ];
+)]
+impl<__impl_generics__> ArrayWithBoolLogicalInplaceMethods<D>
+for __array_type__
+where   D: Dimension {
+    #[duplicate_item(
+        __func_name__           __op__;
+        [ and_inplace ]         [ *lhs && *rhs ];
+        [ or_inplace ]          [ *lhs || *rhs ];
+        [ xor_inplace ]         [ *lhs ^ *rhs ];
+        [ and_not_inplace ]     [ *lhs && !*rhs ];
+    )]
+    fn __func_name__<S, E>(&mut self, other: &ArrayBase<S, E>)
+    where   S: Data<Elem = bool>,
+            E: Dimension,
+    {
+        let other = broadcast_onto(self, other);
+
+        Zip::from(self)
+            .and(other)
+            .for_each(|lhs, rhs| *lhs = __op__);
+    }
+
+    fn not_inplace(&mut self) {
+        self.map_inplace(|value| *value = !*value);
+    }
+}
+
+// =====================================================================================
+
 /// Using BoolArrays as masks for identical sized arrays.
+///
+/// `T` can be a `&mut Array` or a `&mut ArrayViewMut`, with `F` any closure taking
+/// `&mut A`; or a `Zip` of up to 5 producers, with `F` any closure taking their items.
+/// `ndarray` supports at most 6 producers in a `Zip`, one of which is taken up by the
+/// mask itself.
 pub trait ArrayWithBoolMaskMethods<D, A, F, T>: ArrayWithBoolIterMethods<D>
 where
     D: Dimension
@@ -132,24 +369,24 @@ where
     );
 }
 
+// Cannot use &mut ArcArray: it will break sharing
+// See https://docs.rs/ndarray/latest/ndarray/type.ArcArray.html
 #[duplicate_item(
-    __array_type__                       __impl_generics__;
-    [ BoolArray<D> ]                     [ D, A ];
-    [ BoolArcArray<D> ]                  [ D, A ];
-    [ BoolArrayView<'a, D> ]             [ 'a, D, A ];
-    [ BoolArrayViewMut<'a, D> ]          [ 'a, D, A ];
-)]
-#[duplicate_item(
-    __rhs_type__;
-    [ &mut Array<A, D> ];
-    
-    // Cannot use &mut ArcArray: it will break sharing
-    // See https://docs.rs/ndarray/latest/ndarray/type.ArcArray.html
-    // [ ArcArray<A, D> ];
+    __array_type__                  __rhs_type__                        __impl_generics__;
+    [ BoolArray<D> ]                [ &mut Array<A, D> ]                [ D, A, F ];
+    [ BoolArcArray<D> ]             [ &mut Array<A, D> ]                [ D, A, F ];
+    [ BoolArrayView<'a, D> ]        [ &mut Array<A, D> ]                [ 'a, D, A, F ];
+    [ BoolArrayViewMut<'a, D> ]     [ &mut Array<A, D> ]                [ 'a, D, A, F ];
+    [ BoolArray<D> ]                [ &mut ArrayViewMut<'b, A, D> ]     [ 'b, D, A, F ];
+    [ BoolArcArray<D> ]             [ &mut ArrayViewMut<'b, A, D> ]     [ 'b, D, A, F ];
+    [ BoolArrayView<'a, D> ]        [ &mut ArrayViewMut<'b, A, D> ]     [ 'a, 'b, D, A, F ];
+    [ BoolArrayViewMut<'a, D> ]     [ &mut ArrayViewMut<'b, A, D> ]     [ 'a, 'b, D, A, F ];
 )]
-impl<__impl_generics__> ArrayWithBoolMaskMethods<D, A, &dyn Fn(&mut A), __rhs_type__>
+impl<__impl_generics__> ArrayWithBoolMaskMethods<D, A, F, __rhs_type__>
 for __array_type__
-where   D: Dimension {
+where   D: Dimension,
+        F: FnMut(&mut A),
+{
     /// Apply a function to another mutable array, using itself as a mask.
     /// 
     /// The function is only applied to ``array[S<D>]`` if array `self[S<D>]`
@@ -160,7 +397,7 @@ where   D: Dimension {
     fn mask_apply_inplace(
         &self,
         array: __rhs_type__,
-        f: &dyn Fn(&mut A),
+        mut f: F,
     )
     {
         Zip::from(array)
@@ -173,45 +410,370 @@ where   D: Dimension {
     }
 }
 
-// macro_rules! map_impl {
-//     ($([$notlast:ident $($p:ident)*],)+) => {
-//         $(
-//             #[duplicate_item(
-//                 __array_type__                       __impl_generics__;
-//                 [ BoolArray<D> ]                     [ D, A ];
-//                 [ BoolArcArray<D> ]                  [ D, A];
-//                 [ BoolArrayView<'a, D> ]             [ 'a, D, A ];
-//                 [ BoolArrayViewMut<'a, D> ]          [ 'a, D, A ];
-//             )]
-//             #[allow(non_snake_case)]
-//             impl<__impl_generics__, $($p),*> ArrayWithBoolMaskMethods<D, A, &dyn Fn($($p::Item),*), Zip<($($p,)*), D>>
-//             for __array_type__
-//             where   D: Dimension,
-//                     $($p: NdProducer<Dim=D> ,)*
-//             {
-//                 fn mask_apply_inplace(
-//                     &self,
-//                     array: Zip<($($p,)*), D>,
-//                     f: &dyn Fn($($p::Item),*),
-//                 ) {
-//                     array
-//                     .and(self)
-//                     .for_each(| $($p),* , mask | {
-//                         if *mask {
-//                             f($($p),*);
-//                         }
-//                     })
-//                 }
-//             }
-//         )+
-//     }
-// }
-
-// map_impl! {
-//     [true P1],
-//     [true P1 P2],
-//     [true P1 P2 P3],
-//     [true P1 P2 P3 P4],
-//     [true P1 P2 P3 P4 P5],
-//     // [false P1 P2 P3 P4 P5 P6],
-// }
+/// Rayon-parallel counterpart of `ArrayWithBoolMaskMethods`.
+///
+/// `T` can be any of the types accepted by `mask_apply_inplace`, but `F` must be
+/// `Fn + Sync + Send`, as it is called from several threads at once.
+///
+/// Masks with fewer elements than `parallel_threshold()` are applied serially
+/// instead.
+pub trait ArrayWithBoolParallelMaskMethods<D, A, F, T>: ArrayWithBoolMaskMethods<D, A, F, T>
+where
+    D: Dimension
+{
+    fn par_mask_apply_inplace(
+        &self,
+        array: T,
+        f: F,
+    );
+}
+
+#[duplicate_item(
+    __array_type__                  __rhs_type__                        __impl_generics__;
+    [ BoolArray<D> ]                [ &mut Array<A, D> ]                [ D, A, F ];
+    [ BoolArcArray<D> ]             [ &mut Array<A, D> ]                [ D, A, F ];
+    [ BoolArrayView<'a, D> ]        [ &mut Array<A, D> ]                [ 'a, D, A, F ];
+    [ BoolArrayViewMut<'a, D> ]     [ &mut Array<A, D> ]                [ 'a, D, A, F ];
+    [ BoolArray<D> ]                [ &mut ArrayViewMut<'b, A, D> ]     [ 'b, D, A, F ];
+    [ BoolArcArray<D> ]             [ &mut ArrayViewMut<'b, A, D> ]     [ 'b, D, A, F ];
+    [ BoolArrayView<'a, D> ]        [ &mut ArrayViewMut<'b, A, D> ]     [ 'a, 'b, D, A, F ];
+    [ BoolArrayViewMut<'a, D> ]     [ &mut ArrayViewMut<'b, A, D> ]     [ 'a, 'b, D, A, F ];
+)]
+impl<__impl_generics__> ArrayWithBoolParallelMaskMethods<D, A, F, __rhs_type__>
+for __array_type__
+where   D: Dimension,
+        A: Send,
+        F: Fn(&mut A) + Sync + Send,
+{
+    fn par_mask_apply_inplace(
+        &self,
+        array: __rhs_type__,
+        f: F,
+    )
+    {
+        if !use_parallel(self.len()) {
+            self.mask_apply_inplace(array, f);
+            return;
+        }
+
+        Zip::from(array)
+            .and(self)
+            .par_for_each(|v, mask| {
+                if *mask {
+                    f(v);
+                }
+            })
+    }
+}
+
+macro_rules! map_impl {
+    ($([$($p:ident)*],)+) => {
+        $(
+            #[duplicate_item(
+                __array_type__                       __impl_generics__;
+                [ BoolArray<D> ]                     [ D, F ];
+                [ BoolArcArray<D> ]                  [ D, F ];
+                [ BoolArrayView<'a, D> ]             [ 'a, D, F ];
+                [ BoolArrayViewMut<'a, D> ]          [ 'a, D, F ];
+            )]
+            #[allow(non_snake_case)]
+            impl<__impl_generics__, $($p),*> ArrayWithBoolMaskMethods<D, ($($p::Item,)*), F, Zip<($($p,)*), D>>
+            for __array_type__
+            where   D: Dimension,
+                    F: FnMut($($p::Item),*),
+                    $($p: NdProducer<Dim=D> ,)*
+            {
+                /// Apply a function to the items of a `Zip`, using itself as a mask.
+                ///
+                /// The function is only applied to the items at `S<D>` if `self[S<D>]`
+                /// is ``true``.
+                fn mask_apply_inplace(
+                    &self,
+                    array: Zip<($($p,)*), D>,
+                    mut f: F,
+                ) {
+                    array
+                    .and(self)
+                    .for_each(| $($p),* , mask | {
+                        if *mask {
+                            f($($p),*);
+                        }
+                    })
+                }
+            }
+
+            #[duplicate_item(
+                __array_type__                       __impl_generics__;
+                [ BoolArray<D> ]                     [ D, F ];
+                [ BoolArcArray<D> ]                  [ D, F ];
+                [ BoolArrayView<'a, D> ]             [ 'a, D, F ];
+                [ BoolArrayViewMut<'a, D> ]          [ 'a, D, F ];
+            )]
+            #[allow(non_snake_case)]
+            impl<__impl_generics__, $($p),*> ArrayWithBoolParallelMaskMethods<D, ($($p::Item,)*), F, Zip<($($p,)*), D>>
+            for __array_type__
+            where   D: Dimension,
+                    F: Fn($($p::Item),*) + Sync + Send,
+                    $($p: NdProducer<Dim=D> + Send, $p::Item: Send,)*
+            {
+                fn par_mask_apply_inplace(
+                    &self,
+                    array: Zip<($($p,)*), D>,
+                    f: F,
+                ) {
+                    if !use_parallel(self.len()) {
+                        self.mask_apply_inplace(array, f);
+                        return;
+                    }
+
+                    array
+                    .and(self)
+                    .par_for_each(| $($p),* , mask | {
+                        if *mask {
+                            f($($p),*);
+                        }
+                    })
+                }
+            }
+        )+
+    }
+}
+
+map_impl! {
+    [P1],
+    [P1 P2],
+    [P1 P2 P3],
+    [P1 P2 P3 P4],
+    [P1 P2 P3 P4 P5],
+}
+
+// =====================================================================================
+
+/// Selecting and assigning the elements of identical sized arrays, using BoolArrays
+/// as masks; these are `compress` and `putmask` in numpy.
+///
+/// Panics if `array` is not of the same shape as `self`.
+pub trait ArrayWithBoolMaskSelectMethods<D>: ArrayWithBoolIterMethods<D>
+where
+    D: Dimension
+{
+    /// Elements of `array` where `self` is `true`, in logical order.
+    fn mask_select<A, S>(&self, array: &ArrayBase<S, D>) -> Array1<A>
+    where   A: Clone,
+            S: Data<Elem = A>;
+
+    /// Set elements of `array` to `value` where `self` is `true`.
+    fn mask_assign<A, S>(&self, array: &mut ArrayBase<S, D>, value: A)
+    where   A: Clone,
+            S: DataMut<Elem = A>;
+
+    /// Set elements of `array` where `self` is `true` to the elements of `values`, in
+    /// logical order; the inverse of `mask_select`.
+    ///
+    /// Panics if `values` is not of the same length as the number of `true`s.
+    fn mask_assign_from<A, S, T>(&self, array: &mut ArrayBase<S, D>, values: &ArrayBase<T, Ix1>)
+    where   A: Clone,
+            S: DataMut<Elem = A>,
+            T: Data<Elem = A>;
+}
+
+#[duplicate_item(
+    __array_type__                       __impl_generics__;
+    [ BoolArray<D> ]                     [ D ];
+    [ BoolArcArray<D> ]                  [ D ];
+    [ BoolArrayView<'a, D> ]             [ 'a, D ];
+    [ BoolArrayViewMut<'a, D> ]          [ 'a, D ];
+)]
+impl<__impl_generics__> ArrayWithBoolMaskSelectMethods<D>
+for __array_type__
+where   D: Dimension {
+    fn mask_select<A, S>(&self, array: &ArrayBase<S, D>) -> Array1<A>
+    where   A: Clone,
+            S: Data<Elem = A>,
+    {
+        assert_eq!(
+            array.shape(), self.shape(),
+            "mask of shape {:?} cannot be used on array of shape {:?}.",
+            self.shape(), array.shape(),
+        );
+
+        return {
+            array
+            .iter()
+            .zip(self.iter())
+            .filter(|(_, &mask)| mask)
+            .map(|(value, _)| value.clone())
+            .collect()
+        };
+    }
+
+    fn mask_assign<A, S>(&self, array: &mut ArrayBase<S, D>, value: A)
+    where   A: Clone,
+            S: DataMut<Elem = A>,
+    {
+        assert_eq!(
+            array.shape(), self.shape(),
+            "mask of shape {:?} cannot be used on array of shape {:?}.",
+            self.shape(), array.shape(),
+        );
+
+        Zip::from(array)
+            .and(self)
+            .for_each(|v, mask| {
+                if *mask {
+                    *v = value.clone();
+                }
+            })
+    }
+
+    fn mask_assign_from<A, S, T>(&self, array: &mut ArrayBase<S, D>, values: &ArrayBase<T, Ix1>)
+    where   A: Clone,
+            S: DataMut<Elem = A>,
+            T: Data<Elem = A>,
+    {
+        assert_eq!(
+            array.shape(), self.shape(),
+            "mask of shape {:?} cannot be used on array of shape {:?}.",
+            self.shape(), array.shape(),
+        );
+
+        let count = self.count();
+
+        assert_eq!(
+            values.len(), count,
+            "{} values cannot be assigned to {} masked elements.",
+            values.len(), count,
+        );
+
+        let mut values = values.iter();
+
+        Zip::from(array)
+            .and(self)
+            .for_each(|v, mask| {
+                if *mask {
+                    *v = values.next().unwrap().clone();
+                }
+            })
+    }
+}
+
+/// Selecting whole subviews of an array along an axis, using a 1-dimensional
+/// BoolArray as a mask; e.g. selecting rows of an `F64LatLngArray`.
+pub trait ArrayWithBoolMaskSelectAxisMethods: ArrayWithBoolIterMethods<Ix1> {
+    /// Subviews of `array` along `axis` where `self` is `true`, in order.
+    ///
+    /// Panics if `self` is not as long as `axis`.
+    fn mask_select_axis<A, S, E>(&self, array: &ArrayBase<S, E>, axis: Axis) -> Array<A, E>
+    where   A: Clone,
+            S: Data<Elem = A>,
+            E: RemoveAxis;
+}
+
+#[duplicate_item(
+    __array_type__                       __impl_generics__;
+    [ BoolArray1 ]                       [ ];
+    [ BoolArcArray1 ]                    [ ];
+    [ BoolArrayView<'a, Ix1> ]           [ 'a ];
+    [ BoolArrayViewMut<'a, Ix1> ]        [ 'a ];
+)]
+impl<__impl_generics__> ArrayWithBoolMaskSelectAxisMethods
+for __array_type__ {
+    fn mask_select_axis<A, S, E>(&self, array: &ArrayBase<S, E>, axis: Axis) -> Array<A, E>
+    where   A: Clone,
+            S: Data<Elem = A>,
+            E: RemoveAxis,
+    {
+        assert_eq!(
+            self.len(), array.len_of(axis),
+            "mask of length {} cannot be used on axis of length {}.",
+            self.len(), array.len_of(axis),
+        );
+
+        return array.select(axis, &self.indices().to_vec());
+    }
+}
+
+// =====================================================================================
+
+/// Operands of `ArrayWithBoolSelectMethods::if_else`: arrays, views and references to
+/// them, and primitive scalars, which act as 0-dimensional arrays.
+pub trait BroadcastOperand<A> {
+    /// A view of `self`, to be broadcast.
+    fn operand_view(&self) -> ArrayView<'_, A, IxDyn>;
+}
+
+#[duplicate_item(
+    __operand_type__                    __impl_generics__;
+    [ ArrayBase<S, E> ]                 [ A, S, E ];
+    [ &ArrayBase<S, E> ]                [ A, S, E ];
+    [ &mut ArrayBase<S, E> ]            [ A, S, E ];
+)]
+impl<__impl_generics__> BroadcastOperand<A> for __operand_type__
+where   S: Data<Elem = A>,
+        E: Dimension,
+{
+    fn operand_view(&self) -> ArrayView<'_, A, IxDyn> {
+        return self.view().into_dyn();
+    }
+}
+
+#[duplicate_item(
+    __primitive_type__;
+    [ bool ];
+    [ u8 ]; [ u16 ]; [ u32 ]; [ u64 ]; [ u128 ]; [ usize ];
+    [ i8 ]; [ i16 ]; [ i32 ]; [ i64 ]; [ i128 ]; [ isize ];
+    [ f32 ]; [ f64 ];
+)]
+impl BroadcastOperand<__primitive_type__> for __primitive_type__ {
+    fn operand_view(&self) -> ArrayView<'_, __primitive_type__, IxDyn> {
+        return aview0(self).into_dyn();
+    }
+}
+
+/// Choosing elements from two operands using a BoolArray; the three argument form of
+/// `numpy.where`.
+pub trait ArrayWithBoolSelectMethods<D>: ArrayWithBoolIterMethods<D>
+where
+    D: Dimension
+{
+    /// Elements of `if_true` where `self` is `true`, and of `if_false` elsewhere.
+    ///
+    /// Each operand can be an array or view, which is broadcast onto the shape of
+    /// `self`, or a primitive scalar. `select` would be the natural name for this,
+    /// but it is already taken by `ArrayBase::select`.
+    ///
+    /// Panics if either operand cannot be broadcast onto `self`.
+    fn if_else<A, T, F>(&self, if_true: T, if_false: F) -> Array<A, D>
+    where   A: Clone,
+            T: BroadcastOperand<A>,
+            F: BroadcastOperand<A>;
+}
+
+#[duplicate_item(
+    __array_type__                       __impl_generics__;
+    [ BoolArray<D> ]                     [ D ];
+    [ BoolArcArray<D> ]                  [ D ];
+    [ BoolArrayView<'a, D> ]             [ 'a, D ];
+    [ BoolArrayViewMut<'a, D> ]          [ 'a, D ];
+)]
+impl<__impl_generics__> ArrayWithBoolSelectMethods<D>
+for __array_type__
+where   D: Dimension {
+    fn if_else<A, T, F>(&self, if_true: T, if_false: F) -> Array<A, D>
+    where   A: Clone,
+            T: BroadcastOperand<A>,
+            F: BroadcastOperand<A>,
+    {
+        let if_true = if_true.operand_view();
+        let if_false = if_false.operand_view();
+
+        return Zip::from(self)
+                   .and(broadcast_onto(self, &if_true))
+                   .and(broadcast_onto(self, &if_false))
+                   .map_collect(
+                        | mask, if_true, if_false | {
+                            if *mask { if_true.clone() } else { if_false.clone() }
+                        }
+                   );
+    }
+}
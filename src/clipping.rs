@@ -0,0 +1,182 @@
+use duplicate::duplicate_item;
+use ndarray::{
+    ArcArray,
+    Array,
+    ArrayBase,
+    ArrayView,
+    ArrayViewMut,
+    Data,
+    Dimension,
+    Zip,
+};
+use num_traits::Float;
+
+use super::floatarray::{
+    ArrayWithFloatMethods,
+    ArrayWithFloatInplaceMethods,
+};
+use super::generic::{
+    broadcast_onto,
+};
+
+/// `num` limited to `[min, max]`, or `max` if `min > max`; unlike `Float::max` and
+/// `Float::min`, NaN is kept as NaN.
+fn clamp<A>(num: A, min: A, max: A) -> A
+where   A: Float,
+{
+    let num = if num < min { min } else { num };
+
+    return if num > max { max } else { num };
+}
+
+/// `num` with NaN and infinities replaced.
+fn nan_to_num<A>(num: A, nan: A, posinf: A, neginf: A) -> A
+where   A: Float,
+{
+    return if num.is_nan() {
+        nan
+    } else if num == A::infinity() {
+        posinf
+    } else if num == A::neg_infinity() {
+        neginf
+    } else {
+        num
+    };
+}
+
+/// Limiting values to a range, and replacing NaNs and infinities.
+///
+/// NaNs are left untouched by `clamp` and `clip`; use `fill_nan` or `nan_to_num` to
+/// replace them.
+pub trait ArrayWithFloatClipMethods<D, A = f64> : ArrayWithFloatMethods<D, A>
+where   D: Dimension,
+        A: Float,
+{
+    /// Limit every element to `[min, max]`.
+    ///
+    /// Panics if `min > max`, or if either is NaN.
+    fn clamp(&self, min: A, max: A) -> Array<A, D>;
+
+    /// Limit every element to `[min, max]`, where the bounds are arrays broadcast onto
+    /// `self`; e.g. for a `F64LatLngArray`, bounds of shape `(2, )` limit latitudes
+    /// and longitudes separately.
+    ///
+    /// Where a lower bound is greater than its upper bound, the upper bound wins.
+    /// Panics if either bound cannot be broadcast onto `self`.
+    fn clip<S, T, E, F>(&self, min: &ArrayBase<S, E>, max: &ArrayBase<T, F>) -> Array<A, D>
+    where   S: Data<Elem = A>,
+            T: Data<Elem = A>,
+            E: Dimension,
+            F: Dimension;
+
+    /// Replace NaN with `nan`, and infinities with `posinf` and `neginf`. These
+    /// default to `0`, the largest finite value and the smallest finite value
+    /// respectively.
+    fn nan_to_num(&self, nan: Option<A>, posinf: Option<A>, neginf: Option<A>) -> Array<A, D>;
+
+    /// Replace NaN with `value`.
+    fn fill_nan(&self, value: A) -> Array<A, D>;
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatClipMethods<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    fn clamp(&self, min: A, max: A) -> Array<A, D> {
+        assert!(min <= max, "min must be less than or equal to max.");
+
+        return self.mapv(|num| clamp(num, min, max));
+    }
+    fn clip<S, T, E, F>(&self, min: &ArrayBase<S, E>, max: &ArrayBase<T, F>) -> Array<A, D>
+    where   S: Data<Elem = A>,
+            T: Data<Elem = A>,
+            E: Dimension,
+            F: Dimension,
+    {
+        return Zip::from(self)
+                   .and(broadcast_onto(self, min))
+                   .and(broadcast_onto(self, max))
+                   .map_collect(|num, min, max| clamp(*num, *min, *max));
+    }
+    fn nan_to_num(&self, nan: Option<A>, posinf: Option<A>, neginf: Option<A>) -> Array<A, D> {
+        let nan = nan.unwrap_or_else(A::zero);
+        let posinf = posinf.unwrap_or_else(A::max_value);
+        let neginf = neginf.unwrap_or_else(A::min_value);
+
+        return self.mapv(|num| nan_to_num(num, nan, posinf, neginf));
+    }
+    fn fill_nan(&self, value: A) -> Array<A, D> {
+        return self.mapv(|num| if num.is_nan() { value } else { num });
+    }
+}
+
+// =====================================================================================
+
+/// In-place counterparts of `ArrayWithFloatClipMethods`.
+pub trait ArrayWithFloatClipInplaceMethods<D, A = f64> : ArrayWithFloatInplaceMethods<D, A>
+where   D: Dimension,
+        A: Float,
+{
+    fn clamp_inplace(&mut self, min: A, max: A);
+    fn clip_inplace<S, T, E, F>(&mut self, min: &ArrayBase<S, E>, max: &ArrayBase<T, F>)
+    where   S: Data<Elem = A>,
+            T: Data<Elem = A>,
+            E: Dimension,
+            F: Dimension;
+    fn nan_to_num_inplace(&mut self, nan: Option<A>, posinf: Option<A>, neginf: Option<A>);
+    fn fill_nan_inplace(&mut self, value: A);
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatClipInplaceMethods<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    fn clamp_inplace(&mut self, min: A, max: A) {
+        assert!(min <= max, "min must be less than or equal to max.");
+
+        self.map_inplace(|num| *num = clamp(*num, min, max));
+    }
+    fn clip_inplace<S, T, E, F>(&mut self, min: &ArrayBase<S, E>, max: &ArrayBase<T, F>)
+    where   S: Data<Elem = A>,
+            T: Data<Elem = A>,
+            E: Dimension,
+            F: Dimension,
+    {
+        let min = broadcast_onto(self, min);
+        let max = broadcast_onto(self, max);
+
+        Zip::from(self)
+            .and(min)
+            .and(max)
+            .for_each(|num, min, max| *num = clamp(*num, *min, *max));
+    }
+    fn nan_to_num_inplace(&mut self, nan: Option<A>, posinf: Option<A>, neginf: Option<A>) {
+        let nan = nan.unwrap_or_else(A::zero);
+        let posinf = posinf.unwrap_or_else(A::max_value);
+        let neginf = neginf.unwrap_or_else(A::min_value);
+
+        self.map_inplace(|num| *num = nan_to_num(*num, nan, posinf, neginf));
+    }
+    fn fill_nan_inplace(&mut self, value: A) {
+        self.map_inplace(
+            | num | if num.is_nan() {
+                *num = value;
+            }
+        );
+    }
+}
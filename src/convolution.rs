@@ -0,0 +1,243 @@
+use duplicate::duplicate_item;
+use ndarray::{
+    ArcArray1,
+    ArcArray2,
+    Array1,
+    Array2,
+    ArrayBase,
+    ArrayView1,
+    ArrayView2,
+    ArrayViewMut1,
+    ArrayViewMut2,
+    Data,
+    Ix1,
+    Ix2,
+    s,
+};
+use num_traits::Float;
+
+use super::floatarray::{
+    ArrayWithFloatMethods,
+};
+
+/// Size of the output of a 1-dimensional convolution, following `numpy.convolve`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConvolveMode {
+    /// Every point of overlap; of length `N + M - 1`.
+    Full,
+    /// Of length `max(N, M)`, centred on the `Full` output.
+    Same,
+    /// Only where the signal and kernel overlap completely; of length
+    /// `max(N, M) - min(N, M) + 1`.
+    Valid,
+}
+
+/// How to extend an array beyond its borders, following `scipy.ndimage`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BorderMode<A = f64> {
+    /// `k k k k | a b c d | k k k k`
+    Constant(A),
+    /// `d c b a | a b c d | d c b a`
+    Reflect,
+    /// `a b c d | a b c d | a b c d`
+    Wrap,
+    /// `a a a a | a b c d | d d d d`
+    Nearest,
+}
+
+impl<A> Default for BorderMode<A> {
+    fn default() -> Self {
+        return Self::Reflect;
+    }
+}
+
+impl<A> BorderMode<A>
+where   A: Float,
+{
+    /// Index into an axis of length `len` for `index`, or `None` if it takes the
+    /// constant value.
+    fn index(&self, index: isize, len: usize) -> Option<usize> {
+        let len = len as isize;
+
+        if (0..len).contains(&index) {
+            return Some(index as usize);
+        }
+
+        return match self {
+            Self::Constant(_) => None,
+            Self::Reflect => {
+                let index = index.rem_euclid(2 * len);
+                Some((if index >= len { 2 * len - 1 - index } else { index }) as usize)
+            },
+            Self::Wrap => Some(index.rem_euclid(len) as usize),
+            Self::Nearest => Some(index.clamp(0, len - 1) as usize),
+        };
+    }
+
+    /// The value of `array` at `(row, col)`, extended beyond its borders.
+    fn value<S>(&self, array: &ArrayBase<S, Ix2>, row: isize, col: isize) -> A
+    where   S: Data<Elem = A>,
+    {
+        return match (self.index(row, array.nrows()), self.index(col, array.ncols())) {
+            (Some(row), Some(col)) => array[(row, col)],
+            _ => match self {
+                Self::Constant(value) => *value,
+                _ => unreachable!(),
+            },
+        };
+    }
+}
+
+/// Discrete convolution of `signal` and `kernel`.
+fn convolve<A>(signal: ArrayView1<A>, kernel: ArrayView1<A>, mode: ConvolveMode) -> Array1<A>
+where   A: Float,
+{
+    assert!(
+        !signal.is_empty() && !kernel.is_empty(),
+        "Neither the array nor the kernel can be empty."
+    );
+
+    let full_len = signal.len() + kernel.len() - 1;
+    let full = Array1::from_shape_fn(
+        full_len,
+        | k | {
+            let start = (k + 1).saturating_sub(kernel.len());
+            let end = (k + 1).min(signal.len());
+
+            (start..end).fold(A::zero(), |sum, i| sum + signal[i] * kernel[k - i])
+        }
+    );
+
+    let shorter = signal.len().min(kernel.len());
+    let longer = signal.len().max(kernel.len());
+
+    return match mode {
+        ConvolveMode::Full => full,
+        ConvolveMode::Same => {
+            let start = (shorter - 1) / 2;
+            full.slice_move(s![start..start + longer])
+        },
+        ConvolveMode::Valid => full.slice_move(s![shorter - 1..longer]),
+    };
+}
+
+/// Cross-correlation of `array` and `kernel`, whose element `(center_row,
+/// center_col)` is aligned with each element of `array` in turn.
+fn correlate2d<A, S>(
+    array: &ArrayBase<S, Ix2>,
+    kernel: ArrayView2<A>,
+    center: (usize, usize),
+    border: BorderMode<A>,
+) -> Array2<A>
+where   A: Float,
+        S: Data<Elem = A>,
+{
+    return Array2::from_shape_fn(
+        array.raw_dim(),
+        | (row, col) | {
+            kernel.indexed_iter().fold(
+                A::zero(),
+                | sum, ((k_row, k_col), weight) | {
+                    sum + *weight * border.value(
+                        array,
+                        (row + k_row) as isize - center.0 as isize,
+                        (col + k_col) as isize - center.1 as isize,
+                    )
+                }
+            )
+        }
+    );
+}
+
+/// Convolution and cross-correlation of 1-dimensional arrays.
+///
+/// `convolve` defaults to [`ConvolveMode::Full`] and `correlate` to
+/// [`ConvolveMode::Valid`], as in numpy. `correlate` is `convolve` with the kernel
+/// reversed.
+///
+/// Panics if either `self` or `kernel` is empty.
+pub trait ArrayWithFloatConvolveMethods<A = f64> : ArrayWithFloatMethods<Ix1, A>
+where   A: Float,
+{
+    fn convolve<S>(&self, kernel: &ArrayBase<S, Ix1>, mode: Option<ConvolveMode>) -> Array1<A>
+    where   S: Data<Elem = A>;
+    fn correlate<S>(&self, kernel: &ArrayBase<S, Ix1>, mode: Option<ConvolveMode>) -> Array1<A>
+    where   S: Data<Elem = A>;
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array1<A> ]                       [ A ];
+    [ ArcArray1<A> ]                    [ A ];
+    [ ArrayView1<'a, A> ]               [ 'a, A ];
+    [ ArrayViewMut1<'a, A> ]            [ 'a, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatConvolveMethods<A>
+for __array_type__
+where   A: Float,
+{
+    fn convolve<S>(&self, kernel: &ArrayBase<S, Ix1>, mode: Option<ConvolveMode>) -> Array1<A>
+    where   S: Data<Elem = A>,
+    {
+        return convolve(self.view(), kernel.view(), mode.unwrap_or(ConvolveMode::Full));
+    }
+    fn correlate<S>(&self, kernel: &ArrayBase<S, Ix1>, mode: Option<ConvolveMode>) -> Array1<A>
+    where   S: Data<Elem = A>,
+    {
+        return convolve(
+            self.view(),
+            kernel.slice(s![..;-1]),
+            mode.unwrap_or(ConvolveMode::Valid),
+        );
+    }
+}
+
+/// Convolution and cross-correlation of 2-dimensional arrays, such as rasters,
+/// following `scipy.ndimage`.
+///
+/// The output is of the same shape as `self`, with the centre of `kernel` aligned
+/// with each element in turn; beyond the borders of `self`, values are extended
+/// according to `border`, which defaults to [`BorderMode::Reflect`]. For kernels of
+/// even size, the centre is the element just after the middle for `correlate2d`, and
+/// just before it for `convolve2d`, so that the two mirror each other.
+pub trait ArrayWithFloatConvolve2DMethods<A = f64> : ArrayWithFloatMethods<Ix2, A>
+where   A: Float,
+{
+    fn convolve2d<S>(&self, kernel: &ArrayBase<S, Ix2>, border: Option<BorderMode<A>>) -> Array2<A>
+    where   S: Data<Elem = A>;
+    fn correlate2d<S>(&self, kernel: &ArrayBase<S, Ix2>, border: Option<BorderMode<A>>) -> Array2<A>
+    where   S: Data<Elem = A>;
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array2<A> ]                       [ A ];
+    [ ArcArray2<A> ]                    [ A ];
+    [ ArrayView2<'a, A> ]               [ 'a, A ];
+    [ ArrayViewMut2<'a, A> ]            [ 'a, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatConvolve2DMethods<A>
+for __array_type__
+where   A: Float,
+{
+    fn convolve2d<S>(&self, kernel: &ArrayBase<S, Ix2>, border: Option<BorderMode<A>>) -> Array2<A>
+    where   S: Data<Elem = A>,
+    {
+        return correlate2d(
+            self,
+            kernel.slice(s![..;-1, ..;-1]),
+            ((kernel.nrows().max(1) - 1) / 2, (kernel.ncols().max(1) - 1) / 2),
+            border.unwrap_or_default(),
+        );
+    }
+    fn correlate2d<S>(&self, kernel: &ArrayBase<S, Ix2>, border: Option<BorderMode<A>>) -> Array2<A>
+    where   S: Data<Elem = A>,
+    {
+        return correlate2d(
+            self,
+            kernel.view(),
+            (kernel.nrows() / 2, kernel.ncols() / 2),
+            border.unwrap_or_default(),
+        );
+    }
+}
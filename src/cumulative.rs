@@ -0,0 +1,99 @@
+use duplicate::duplicate_item;
+use ndarray::{
+    ArcArray,
+    Array,
+    ArrayView,
+    ArrayViewMut,
+    Axis,
+    Dimension,
+};
+use num_traits::Float;
+
+use super::floatarray::{
+    ArrayWithFloatMethods,
+};
+
+/// Running accumulation of `array` along `axis`, each element replaced by
+/// `func(previous, current)`.
+fn accumulate<A, D, F>(array: ArrayView<A, D>, axis: Axis, func: F) -> Array<A, D>
+where   A: Float,
+        D: Dimension,
+        F: Fn(A, A) -> A,
+{
+    let mut result = array.to_owned();
+    result.accumulate_axis_inplace(axis, |prev, curr| *curr = func(*prev, *curr));
+
+    return result;
+}
+
+/// `Float::max`, but NaN if either side is NaN.
+fn max_propagate_nan<A>(lhs: A, rhs: A) -> A
+where   A: Float,
+{
+    return if lhs.is_nan() || rhs.is_nan() { A::nan() } else { lhs.max(rhs) };
+}
+
+/// `Float::min`, but NaN if either side is NaN.
+fn min_propagate_nan<A>(lhs: A, rhs: A) -> A
+where   A: Float,
+{
+    return if lhs.is_nan() || rhs.is_nan() { A::nan() } else { lhs.min(rhs) };
+}
+
+/// Scan-style operations along an axis, returning arrays of the same shape as `self`.
+///
+/// Once a NaN is encountered, every subsequent element along the axis is NaN. The
+/// `nan` variants skip NaNs instead: `nancumsum_axis` and `nancumprod_axis` treat them
+/// as `0` and `1` respectively, and `nancummax_axis` and `nancummin_axis` carry the
+/// running extreme over them, leaving only leading NaNs as NaN.
+pub trait ArrayWithFloatCumulativeMethods<D, A = f64> : ArrayWithFloatMethods<D, A>
+where   D: Dimension,
+        A: Float,
+{
+    #[duplicate_item(
+        __func_name__;
+        [ cumsum_axis ];
+        [ cumprod_axis ];
+        [ cummax_axis ];
+        [ cummin_axis ];
+        [ nancumsum_axis ];
+        [ nancumprod_axis ];
+        [ nancummax_axis ];
+        [ nancummin_axis ];
+    )]
+    fn __func_name__(&self, axis: Axis) -> Array<A, D>;
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatCumulativeMethods<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    #[duplicate_item(
+        __func_name__           __func__;
+        [ cumsum_axis ]         [ |prev, curr| prev + curr ];
+        [ cumprod_axis ]        [ |prev, curr| prev * curr ];
+        [ cummax_axis ]         [ max_propagate_nan ];
+        [ cummin_axis ]         [ min_propagate_nan ];
+        [ nancummax_axis ]      [ A::max ];
+        [ nancummin_axis ]      [ A::min ];
+    )]
+    fn __func_name__(&self, axis: Axis) -> Array<A, D> {
+        return accumulate(self.view(), axis, __func__);
+    }
+    fn nancumsum_axis(&self, axis: Axis) -> Array<A, D> {
+        return self.mapv(|num| if num.is_nan() { A::zero() } else { num })
+                   .cumsum_axis(axis);
+    }
+    fn nancumprod_axis(&self, axis: Axis) -> Array<A, D> {
+        return self.mapv(|num| if num.is_nan() { A::one() } else { num })
+                   .cumprod_axis(axis);
+    }
+}
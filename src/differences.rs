@@ -0,0 +1,167 @@
+use duplicate::duplicate_item;
+use ndarray::{
+    ArcArray,
+    Array,
+    Array1,
+    ArrayView,
+    ArrayView1,
+    ArrayViewMut,
+    Axis,
+    Dimension,
+    Slice,
+    Zip,
+};
+use num_traits::Float;
+
+use super::floatarray::{
+    ArrayWithFloatMethods,
+};
+use super::generic::{
+    map_lanes,
+};
+
+/// Gradient of `lane`, sampled at a uniform `spacing`.
+fn gradient_uniform<A>(lane: ArrayView1<A>, spacing: A) -> Vec<A>
+where   A: Float,
+{
+    let last = lane.len() - 1;
+    let two = A::from(2).unwrap();
+
+    return (0..=last).map(
+        | index | match index {
+            0 => (lane[1] - lane[0]) / spacing,
+            index if index == last => (lane[last] - lane[last - 1]) / spacing,
+            index => (lane[index + 1] - lane[index - 1]) / (two * spacing),
+        }
+    ).collect();
+}
+
+/// Gradient of `lane`, sampled at `coords`; the interior uses second order accurate
+/// central differences for non-uniform spacing, as numpy does.
+fn gradient_coords<A>(lane: ArrayView1<A>, coords: ArrayView1<A>) -> Vec<A>
+where   A: Float,
+{
+    let last = lane.len() - 1;
+
+    return (0..=last).map(
+        | index | match index {
+            0 => (lane[1] - lane[0]) / (coords[1] - coords[0]),
+            index if index == last => (lane[last] - lane[last - 1]) / (coords[last] - coords[last - 1]),
+            index => {
+                let hd = coords[index] - coords[index - 1];
+                let hs = coords[index + 1] - coords[index];
+
+                (
+                    hd.powi(2) * lane[index + 1]
+                    - hs.powi(2) * lane[index - 1]
+                    + (hs.powi(2) - hd.powi(2)) * lane[index]
+                ) / (hs * hd * (hd + hs))
+            },
+        }
+    ).collect();
+}
+
+/// Finite differences and numerical gradients along an axis.
+///
+/// The gradient uses central differences in the interior and one-sided differences at
+/// the edges, following `numpy.gradient`. The samples are either at a uniform
+/// `spacing` along `axis`, or at the coordinates `coords`, which must be as long as
+/// `axis`. There must be at least 2 samples along `axis`.
+///
+/// `gradient_f64`, `gradient_arr` and `gradient_arrv` accept the spacing as a scalar,
+/// an `Array1` and an `ArrayView1` respectively; see
+/// [`ArrayWithFloatGradientMethods`] for a single `gradient` method accepting all of
+/// them.
+pub trait ArrayWithFloatDifferenceMethods<D, A = f64> : ArrayWithFloatMethods<D, A>
+where   D: Dimension,
+        A: Float,
+{
+    /// The `n`-th discrete difference along `axis`; the result is `n` shorter along
+    /// `axis`, or empty if `n` is not less than its length.
+    fn diff(&self, n: usize, axis: Axis) -> Array<A, D>;
+
+    fn gradient_f64(&self, axis: Axis, spacing: A) -> Array<A, D>;
+    fn gradient_arr(&self, axis: Axis, coords: Array1<A>) -> Array<A, D>;
+    fn gradient_arrv<'b>(&self, axis: Axis, coords: ArrayView1<'b, A>) -> Array<A, D>;
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatDifferenceMethods<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    fn diff(&self, n: usize, axis: Axis) -> Array<A, D> {
+        let mut result = self.to_owned();
+
+        for _ in 0..n {
+            let len = result.len_of(axis);
+
+            if len == 0 {
+                break;
+            }
+
+            result = Zip::from(result.slice_axis(axis, Slice::from(1..)))
+                         .and(result.slice_axis(axis, Slice::from(..len - 1)))
+                         .map_collect(|next, prev| *next - *prev);
+        }
+
+        return result;
+    }
+
+    fn gradient_f64(&self, axis: Axis, spacing: A) -> Array<A, D> {
+        assert!(self.len_of(axis) >= 2, "At least 2 samples are required along axis.");
+
+        return map_lanes(self.view(), axis, |lane| gradient_uniform(lane, spacing));
+    }
+    fn gradient_arr(&self, axis: Axis, coords: Array1<A>) -> Array<A, D> {
+        return self.gradient_arrv(axis, coords.view());
+    }
+    fn gradient_arrv<'b>(&self, axis: Axis, coords: ArrayView1<'b, A>) -> Array<A, D> {
+        assert!(self.len_of(axis) >= 2, "At least 2 samples are required along axis.");
+        assert_eq!(
+            coords.len(), self.len_of(axis),
+            "coords must be as long as axis."
+        );
+
+        return map_lanes(self.view(), axis, |lane| gradient_coords(lane, coords.view()));
+    }
+}
+
+/// Allow `gradient` to accept a scalar spacing, an `Array1` or an `ArrayView1` of
+/// coordinates alike.
+pub trait ArrayWithFloatGradientMethods<T, D, A = f64> : ArrayWithFloatDifferenceMethods<D, A>
+where   D: Dimension,
+        A: Float,
+{
+    fn gradient(&self, axis: Axis, spacing: T) -> Array<A, D>;
+}
+
+#[duplicate_item(
+    __rhs_type__                __gradient__;
+    [ A ]                       [ gradient_f64 ];
+    [ Array1<A> ]               [ gradient_arr ];
+    [ ArrayView1<'_, A> ]       [ gradient_arrv ];
+)]
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatGradientMethods<__rhs_type__, D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    fn gradient(&self, axis: Axis, spacing: __rhs_type__) -> Array<A, D> {
+        return self.__gradient__(axis, spacing);
+    }
+}
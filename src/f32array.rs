@@ -0,0 +1,30 @@
+use ndarray::{
+    ArcArray,
+    Array,
+    ArrayView,
+    ArrayViewMut,
+    Ix1,
+    Ix2,
+};
+
+// `f32` arrays share the `Float` traits with `f64` arrays, see `floatarray`.
+pub type F32Array<D> = Array<f32, D>;
+pub type F32Array1 = F32Array<Ix1>;
+pub type F32Array2 = F32Array<Ix2>;
+pub type F32ArcArray<D> = ArcArray<f32, D>;
+pub type F32ArcArray1 = F32ArcArray<Ix1>;
+pub type F32ArcArray2 = F32ArcArray<Ix2>;
+pub type F32ArrayView<'a, D> = ArrayView<'a, f32, D>;
+pub type F32ArrayViewMut<'a, D> = ArrayViewMut<'a, f32, D>;
+
+pub type OptionF32Array<D> = Array<Option<f32>, D>;
+pub type OptionF32Array1 = OptionF32Array<Ix1>;
+pub type OptionF32Array2 = OptionF32Array<Ix2>;
+
+pub type F32LatLng = F32Array1;
+pub type F32LatLngView<'a> = F32ArrayView<'a, Ix1>;
+pub type F32LatLngViewMut<'a> = F32ArrayViewMut<'a, Ix1>;
+pub type F32LatLngArray = F32Array2;
+pub type F32LatLngArcArray = F32ArcArray2;
+pub type F32LatLngArrayView<'a> = F32ArrayView<'a, Ix2>;
+pub type F32LatLngArrayViewMut<'a> = F32ArrayViewMut<'a, Ix2>;
@@ -0,0 +1,1276 @@
+use std::f64;
+use std::cmp::Ordering;
+
+use duplicate::duplicate_item;
+use ndarray::{
+    ArcArray,
+    ArcArray2,
+    Array,
+    Array2,
+    ArrayBase,
+    ArrayView,
+    ArrayViewMut,
+    ArrayViewMut2,
+    Axis,
+    Data,
+    Dimension,
+    Ix2,
+    Zip,
+};
+use num_traits::Float;
+
+use super::generic::{
+    ArrayProxiedMethods,
+    broadcast_onto,
+    broadcast_pair,
+};
+use super::boolarray::{
+    BoolArray,
+};
+use super::parallel::{
+    use_parallel,
+};
+
+/// `Float` counterpart of `f64::div_euclid`, which `num_traits::Float` lacks.
+fn div_euclid<A>(lhs: A, rhs: A) -> A
+where   A: Float {
+    let q = (lhs / rhs).trunc();
+
+    return match lhs % rhs < A::zero() {
+        true if rhs > A::zero() => q - A::one(),
+        true => q + A::one(),
+        false => q,
+    };
+}
+
+/// `|lhs - rhs|`, as `abs_sub` has always been implemented in this crate.
+fn abs_sub<A>(lhs: A, rhs: A) -> A
+where   A: Float {
+    return (lhs - rhs).abs();
+}
+
+/// `Float` counterpart of `f64::rem_euclid`, which `num_traits::Float` lacks.
+fn rem_euclid<A>(lhs: A, rhs: A) -> A
+where   A: Float {
+    let r = lhs % rhs;
+
+    return match r < A::zero() {
+        true => r + rhs.abs(),
+        false => r,
+    };
+}
+
+/// Trait for Arrays supporting `num_traits::Float` operations.
+/// 
+/// The element type `A` defaults to `f64`; this trait is re-exported as
+/// `ArrayWithF64Methods` for `f64` arrays. The returned arrays are always of the
+/// same element type as the base array.
+///
+/// The `_f64` suffix of binary methods such as `hypot_f64` means that `other` is a
+/// scalar of type `A`, not necessarily an `f64`; e.g. `F32Array::hypot_f64` takes an
+/// `f32`. The suffix is kept from when these methods were `f64` only. The same goes
+/// for the `_f64_inplace` and `par_*_f64` methods.
+pub trait ArrayWithFloatMethods<D, A = f64>: ArrayProxiedMethods<D, A>
+where
+    D: Dimension,
+    A: Float,
+{
+    fn abs(&self) -> Array<A, D>;
+
+    // Binary methods such as atan2 have 3 cases - other could be scalar or an array
+    // or an array view. Because their implementation is completely different and
+    // there is no type overloading in Rust, we split them into three, much like
+    // powi and powf. The generic methods at the end of this trait, and
+    // `ArrayWithFloatAtan2Methods` for atan2, then dispatch them back under their
+    // original names by the type of `other`; see `FloatBinaryOperand`.
+    //
+    // Arrays of different shapes are broadcast against each other.
+    fn abs_sub_f64(&self, other: A) -> Array<A, D>;
+    fn abs_sub_arr(&self, other: Array<A, D>) -> Array<A, D>;
+    fn abs_sub_arrv<'b>(&self, other: ArrayView<'b, A, D>) -> Array<A, D>;
+
+    fn acos(&self) -> Array<A, D>;
+    fn acosh(&self) -> Array<A, D>;
+    fn asin(&self) -> Array<A, D>;
+    fn asinh(&self) -> Array<A, D>;
+    fn atan(&self) -> Array<A, D>;
+
+    fn atan2_f64(&self, other: A) -> Array<A, D>;
+    fn atan2_arr(&self, other: Array<A, D>) -> Array<A, D>;
+    fn atan2_arrv<'b>(&self, other: ArrayView<'b, A, D>) -> Array<A, D>;
+
+    fn atanh(&self) -> Array<A, D>;
+    fn cbrt(&self) -> Array<A, D>;
+    fn ceil(&self) -> Array<A, D>;
+
+    fn copysign_f64(&self, other: A) -> Array<A, D>;
+    fn copysign_arr(&self, other: Array<A, D>) -> Array<A, D>;
+    fn copysign_arrv<'b>(&self, other: ArrayView<'b, A, D>) -> Array<A, D>;
+
+    fn cos(&self) -> Array<A, D>;
+    fn cosh(&self) -> Array<A, D>;
+
+    fn div_euclid_f64(&self, other: A) -> Array<A, D>;
+    fn div_euclid_arr(&self, other: Array<A, D>) -> Array<A, D>;
+    fn div_euclid_arrv<'b>(&self, other: ArrayView<'b, A, D>) -> Array<A, D>;
+
+    fn exp(&self) -> Array<A, D>;
+    fn exp2(&self) -> Array<A, D>;
+    fn exp_m1(&self) -> Array<A, D>;
+    fn floor(&self) -> Array<A, D>;
+    fn fract(&self) -> Array<A, D>;
+
+    fn hypot_f64(&self, other: A) -> Array<A, D>;
+    fn hypot_arr(&self, other: Array<A, D>) -> Array<A, D>;
+    fn hypot_arrv<'b>(&self, other: ArrayView<'b, A, D>) -> Array<A, D>;
+
+    fn ln(&self) -> Array<A, D>;
+    fn ln_1p(&self) -> Array<A, D>;
+
+    fn log_f64(&self, other: A) -> Array<A, D>;
+    fn log_arr(&self, other: Array<A, D>) -> Array<A, D>;
+    fn log_arrv<'b>(&self, other: ArrayView<'b, A, D>) -> Array<A, D>;
+
+    fn log10(&self) -> Array<A, D>;
+    fn log2(&self) -> Array<A, D>;
+
+    fn mul_add_f64(&self, a: A, b: A) -> Array<A, D>;
+    fn mul_add_arr(&self, a: Array<A, D>, b: Array<A, D>) -> Array<A, D>;
+    fn mul_add_arrv<'b, 'c>(&self, a: ArrayView<'b, A, D>, b: ArrayView<'c, A, D>) -> Array<A, D>;
+
+    fn powf_f64(&self, other: A) -> Array<A, D>;
+    fn powf_arr(&self, other: Array<A, D>) -> Array<A, D>;
+    fn powf_arrv<'b>(&self, other: ArrayView<'b, A, D>) -> Array<A, D>;
+
+    fn powi(&self, n: i32) -> Array<A, D>;
+
+    fn rem_euclid_f64(&self, other: A) -> Array<A, D>;
+    fn rem_euclid_arr(&self, other: Array<A, D>) -> Array<A, D>;
+    fn rem_euclid_arrv<'b>(&self, other: ArrayView<'b, A, D>) -> Array<A, D>;
+
+    fn round(&self) -> Array<A, D>;
+    fn signum(&self) -> Array<A, D>;
+    fn sin(&self) -> Array<A, D>;
+    fn sin_cos(&self) -> Array<(A, A), D>;
+    fn sinh(&self) -> Array<A, D>;
+    fn sqrt(&self) -> Array<A, D>;
+    fn tan(&self) -> Array<A, D>;
+    fn tanh(&self) -> Array<A, D>;
+    fn trunc(&self) -> Array<A, D>;
+
+    #[duplicate_item(
+        __func_name__       __func_of__;
+        [ abs_sub ]         [ abs_sub_of ];
+        [ copysign ]        [ copysign_of ];
+        [ div_euclid ]      [ div_euclid_of ];
+        [ hypot ]           [ hypot_of ];
+        [ log ]             [ log_of ];
+        [ powf ]            [ powf_of ];
+        [ rem_euclid ]      [ rem_euclid_of ];
+    )]
+    fn __func_name__<T>(&self, other: T) -> Array<A, D>
+    where   T: FloatBinaryOperand<A, D>,
+            Self: Sized,
+    {
+        return other.__func_of__(self);
+    }
+    fn mul_add<T>(&self, a: T, b: T) -> Array<A, D>
+    where   T: FloatBinaryOperand<A, D>,
+            Self: Sized,
+    {
+        return a.mul_add_of(b, self);
+    }
+}
+
+
+/// An additional trait that will be implemented thrice, once for `A`,
+/// once for `Array<A, D>` and once for `ArrayView<A, D>` so that `atan2`
+/// will be available as a method on its own.
+pub trait ArrayWithFloatAtan2Methods<T, D, A = f64> : ArrayWithFloatMethods<D, A>
+where   D: Dimension,
+        A: Float,
+{
+    fn atan2(&self, other: T) -> Array<A, D>;
+}
+
+/// Right-hand operands of the binary methods of `ArrayWithFloatMethods`, such as
+/// `hypot`: a scalar `A`, an `Array<A, D>` or an `ArrayView<A, D>`.
+///
+/// Each method calls the `_f64`, `_arr` or `_arrv` variant of `array` matching the
+/// type of the operand.
+pub trait FloatBinaryOperand<A, D>
+where   D: Dimension,
+        A: Float,
+{
+    #[duplicate_item(
+        __func_name__;
+        [ abs_sub_of ];
+        [ copysign_of ];
+        [ div_euclid_of ];
+        [ hypot_of ];
+        [ log_of ];
+        [ powf_of ];
+        [ rem_euclid_of ];
+    )]
+    fn __func_name__<X>(self, array: &X) -> Array<A, D>
+    where   X: ArrayWithFloatMethods<D, A>;
+    fn mul_add_of<X>(self, b: Self, array: &X) -> Array<A, D>
+    where   X: ArrayWithFloatMethods<D, A>;
+}
+
+/// Implements all `Float` methods for ArrayBase.
+/// 
+/// This `impl` is trait bound to `Float` Arrays only.
+/// use duplicate::duplicate_item;
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatMethods<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    fn abs(&self) -> Array<A, D> {
+        return ArrayBase::map(self,|num| num.abs());
+    }
+    fn abs_sub_f64(&self, other: A) -> Array<A, D> {
+        return self.map(|num| (*num - other).abs());
+    }
+    fn abs_sub_arr(&self, other: Array<A, D>) -> Array<A, D> {
+        return self.abs_sub_arrv(other.view());
+    }
+    fn abs_sub_arrv<'b>(&self, other: ArrayView<'b, A, D>) -> Array<A, D> {
+        let (x, y) = broadcast_pair(self, &other);
+
+        return Zip::from(x)
+                    .and(y)
+                    .map_collect(
+                        move |x, y| (*x - *y).abs()
+                    );
+    }
+    fn acos(&self) -> Array<A, D> {
+        return self.map(|num| num.acos());
+    }
+    fn acosh(&self) -> Array<A, D> {
+        return self.map(|num| num.acosh());
+    }
+    fn asin(&self) -> Array<A, D> {
+        return self.map(|num| num.asin());
+    }
+    fn asinh(&self) -> Array<A, D> {
+        return self.map(|num| num.asinh());
+    }
+    fn atan(&self) -> Array<A, D> {
+        return self.map(|num| num.atan());
+    }
+    #[duplicate_item(
+        __func_name__           __func__;
+        [ atan2_f64 ]           [ A::atan2 ];
+        [ copysign_f64 ]        [ A::copysign ];
+        [ div_euclid_f64 ]      [ div_euclid ];
+        [ hypot_f64 ]           [ A::hypot ];
+        [ log_f64 ]             [ A::log ];
+        [ powf_f64 ]            [ A::powf ];
+        [ rem_euclid_f64 ]      [ rem_euclid ];
+    )]
+    fn __func_name__(&self, other: A) -> Array<A, D> {
+        return self.map(|num| __func__(*num, other));
+    }
+    #[duplicate_item(
+        __func_name__           __func_arrv__;
+        [ atan2_arr ]           [ atan2_arrv ];
+        [ copysign_arr ]        [ copysign_arrv ];
+        [ div_euclid_arr ]      [ div_euclid_arrv ];
+        [ hypot_arr ]           [ hypot_arrv ];
+        [ log_arr ]             [ log_arrv ];
+        [ powf_arr ]            [ powf_arrv ];
+        [ rem_euclid_arr ]      [ rem_euclid_arrv ];
+    )]
+    fn __func_name__(&self, other: Array<A, D>) -> Array<A, D> {
+        return self.__func_arrv__(other.view());
+    }
+    #[duplicate_item(
+        __func_name__           __func__;
+        [ atan2_arrv ]          [ A::atan2 ];
+        [ copysign_arrv ]       [ A::copysign ];
+        [ div_euclid_arrv ]     [ div_euclid ];
+        [ hypot_arrv ]          [ A::hypot ];
+        [ log_arrv ]            [ A::log ];
+        [ powf_arrv ]           [ A::powf ];
+        [ rem_euclid_arrv ]     [ rem_euclid ];
+    )]
+    fn __func_name__<'b>(&self, other: ArrayView<'b, A, D>) -> Array<A, D> {
+        // From https://docs.rs/ndarray/latest/src/ndarray/impl_ops.rs.html#115.
+        // Zip is the only way to do elementwise operations between 2 arrays;
+        // `other` is broadcast first so that arrays of different shapes can
+        // still be zipped together.
+        let (x, y) = broadcast_pair(self, &other);
+
+        return Zip::from(x)
+                    .and(y)
+                    .map_collect(
+                        move |x, y| __func__(*x, *y)
+                    );
+    }
+    fn atanh(&self) -> Array<A, D> {
+        return self.map(|num| num.atanh());
+    }
+    fn cbrt(&self) -> Array<A, D> {
+        return self.map(|num| num.cbrt());
+    }
+    fn ceil(&self) -> Array<A, D> {
+        return self.map(|num| num.ceil());
+    }
+    fn cos(&self) -> Array<A, D> {
+        return self.map(|num| num.cos());
+    }
+    fn cosh(&self) -> Array<A, D> {
+        return self.map(|num| num.cosh());
+    }
+    fn exp(&self) -> Array<A, D> {
+        return self.map(|num| num.exp());
+    }
+    fn exp2(&self) -> Array<A, D> {
+        return self.map(|num| num.exp2());
+    }
+    fn exp_m1(&self) -> Array<A, D> {
+        return self.map(|num| num.exp_m1());
+    }
+    fn floor(&self) -> Array<A, D> {
+        return self.map(|num| num.floor());
+    }
+    fn fract(&self) -> Array<A, D> {
+        return self.map(|num| num.fract());
+    }
+    fn ln(&self) -> Array<A, D> {
+        return self.map(|num| num.ln());
+    }
+    fn ln_1p(&self) -> Array<A, D> {
+        return self.map(|num| num.ln_1p());
+    }
+    fn log10(&self) -> Array<A, D> {
+        return self.map(|num| num.log10());
+    }
+    fn log2(&self) -> Array<A, D> {
+        return self.map(|num| num.log2());
+    }
+    fn mul_add_f64(&self, a: A, b: A) -> Array<A, D> {
+        return self.map(|num| num.mul_add(a, b));
+    }
+    fn mul_add_arr(&self, a: Array<A, D>, b: Array<A, D>) -> Array<A, D> {
+        return self.mul_add_arrv(a.view(), b.view());
+    }
+    fn mul_add_arrv<'b, 'c>(&self, a: ArrayView<'b, A, D>, b: ArrayView<'c, A, D>) -> Array<A, D> {
+        let (x, a) = broadcast_pair(self, &a);
+        let (x, b) = broadcast_pair(&x, &b);
+
+        return Zip::from(&x)
+                    .and(a.broadcast(x.raw_dim()).unwrap())
+                    .and(b)
+                    .map_collect(
+                        move |x, a, b| x.mul_add(*a, *b)
+                    );
+    }
+    fn powi(&self, n: i32) -> Array<A, D> {
+        return self.map(|num| num.powi(n));
+    }
+    fn round(&self) -> Array<A, D> {
+        return self.map(|num| num.round());
+    }
+    fn signum(&self) -> Array<A, D> {
+        return self.map(|num| num.signum());
+    }
+    fn sin(&self) -> Array<A, D> {
+        return self.map(|num| num.sin());
+    }
+    fn sin_cos(&self) -> Array<(A, A), D> {
+        return self.map(|num| num.sin_cos());
+    }
+    fn sinh(&self) -> Array<A, D> {
+        return self.map(|num| num.sinh());
+    }
+    fn sqrt(&self) -> Array<A, D> {
+        return self.map(|num| num.sqrt());
+    }
+    fn tan(&self) -> Array<A, D> {
+        return self.map(|num| num.tan());
+    }
+    fn tanh(&self) -> Array<A, D> {
+        return self.map(|num| num.tanh());
+    }
+    fn trunc(&self) -> Array<A, D> {
+        return self.map(|num| num.trunc());
+    }
+}
+
+#[duplicate_item(
+    __rhs_type__                        __func_name__;
+    [ A ]                               [ atan2_f64 ];
+    [ Array<A, D> ]                     [ atan2_arr ];
+    [ ArrayView<'_, A, D> ]             [ atan2_arrv ];
+)]
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatAtan2Methods<__rhs_type__, D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    fn atan2(&self, other: __rhs_type__) -> Array<A, D> {
+        return self.__func_name__(other);
+    }
+}
+
+#[duplicate_item(
+    __rhs_type__                __impl_generics__   __abs_sub__         __copysign__        __div_euclid__          __hypot__       __log__         __mul_add__         __powf__        __rem_euclid__;
+    [ A ]                       [ D, A ]            [ abs_sub_f64 ]     [ copysign_f64 ]    [ div_euclid_f64 ]      [ hypot_f64 ]   [ log_f64 ]     [ mul_add_f64 ]     [ powf_f64 ]    [ rem_euclid_f64 ];
+    [ Array<A, D> ]             [ D, A ]            [ abs_sub_arr ]     [ copysign_arr ]    [ div_euclid_arr ]      [ hypot_arr ]   [ log_arr ]     [ mul_add_arr ]     [ powf_arr ]    [ rem_euclid_arr ];
+    [ ArrayView<'b, A, D> ]     [ 'b, D, A ]        [ abs_sub_arrv ]    [ copysign_arrv ]   [ div_euclid_arrv ]     [ hypot_arrv ]  [ log_arrv ]    [ mul_add_arrv ]    [ powf_arrv ]   [ rem_euclid_arrv ];
+)]
+impl<__impl_generics__> FloatBinaryOperand<A, D>
+for __rhs_type__
+where   D: Dimension,
+        A: Float,
+{
+    #[duplicate_item(
+        __func_name__       __func__;
+        [ abs_sub_of ]      [ __abs_sub__ ];
+        [ copysign_of ]     [ __copysign__ ];
+        [ div_euclid_of ]   [ __div_euclid__ ];
+        [ hypot_of ]        [ __hypot__ ];
+        [ log_of ]          [ __log__ ];
+        [ powf_of ]         [ __powf__ ];
+        [ rem_euclid_of ]   [ __rem_euclid__ ];
+    )]
+    fn __func_name__<X>(self, array: &X) -> Array<A, D>
+    where   X: ArrayWithFloatMethods<D, A>
+    {
+        return array.__func__(self);
+    }
+    fn mul_add_of<X>(self, b: Self, array: &X) -> Array<A, D>
+    where   X: ArrayWithFloatMethods<D, A>
+    {
+        return array.__mul_add__(self, b);
+    }
+}
+
+// =====================================================================================
+
+/// In-place counterparts of `ArrayWithFloatMethods`.
+///
+/// Instead of allocating a new array, these mutate the elements of `self` directly,
+/// much like `ArrayWithFloatLatLngMethods::normalize`. `sin_cos` has no counterpart,
+/// as it changes the element type.
+///
+/// `ArcArray`s are made unique before being mutated; if their data is shared with
+/// other `ArcArray`s, it will be cloned first.
+pub trait ArrayWithFloatInplaceMethods<D, A = f64>: ArrayWithFloatMethods<D, A>
+where
+    D: Dimension,
+    A: Float,
+{
+    #[duplicate_item(
+        __func_name__;
+        [ abs_inplace ];
+        [ acos_inplace ];
+        [ acosh_inplace ];
+        [ asin_inplace ];
+        [ asinh_inplace ];
+        [ atan_inplace ];
+        [ atanh_inplace ];
+        [ cbrt_inplace ];
+        [ ceil_inplace ];
+        [ cos_inplace ];
+        [ cosh_inplace ];
+        [ exp_inplace ];
+        [ exp2_inplace ];
+        [ exp_m1_inplace ];
+        [ floor_inplace ];
+        [ fract_inplace ];
+        [ ln_inplace ];
+        [ ln_1p_inplace ];
+        [ log10_inplace ];
+        [ log2_inplace ];
+        [ round_inplace ];
+        [ signum_inplace ];
+        [ sin_inplace ];
+        [ sinh_inplace ];
+        [ sqrt_inplace ];
+        [ tan_inplace ];
+        [ tanh_inplace ];
+        [ trunc_inplace ];
+    )]
+    fn __func_name__(&mut self);
+
+    fn powi_inplace(&mut self, n: i32);
+
+    // As with `ArrayWithFloatMethods`, binary methods are split into three, and
+    // dispatched by `ArrayWithFloatBinaryInplaceMethods`. `other` is broadcast to
+    // the shape of `self`, which cannot change.
+    #[duplicate_item(
+        __func_name__;
+        [ abs_sub_f64_inplace ];
+        [ atan2_f64_inplace ];
+        [ copysign_f64_inplace ];
+        [ div_euclid_f64_inplace ];
+        [ hypot_f64_inplace ];
+        [ log_f64_inplace ];
+        [ powf_f64_inplace ];
+        [ rem_euclid_f64_inplace ];
+    )]
+    fn __func_name__(&mut self, other: A);
+    #[duplicate_item(
+        __func_name__;
+        [ abs_sub_arr_inplace ];
+        [ atan2_arr_inplace ];
+        [ copysign_arr_inplace ];
+        [ div_euclid_arr_inplace ];
+        [ hypot_arr_inplace ];
+        [ log_arr_inplace ];
+        [ powf_arr_inplace ];
+        [ rem_euclid_arr_inplace ];
+    )]
+    fn __func_name__(&mut self, other: Array<A, D>);
+    #[duplicate_item(
+        __func_name__;
+        [ abs_sub_arrv_inplace ];
+        [ atan2_arrv_inplace ];
+        [ copysign_arrv_inplace ];
+        [ div_euclid_arrv_inplace ];
+        [ hypot_arrv_inplace ];
+        [ log_arrv_inplace ];
+        [ powf_arrv_inplace ];
+        [ rem_euclid_arrv_inplace ];
+    )]
+    fn __func_name__<'b>(&mut self, other: ArrayView<'b, A, D>);
+
+    fn mul_add_f64_inplace(&mut self, a: A, b: A);
+    fn mul_add_arr_inplace(&mut self, a: Array<A, D>, b: Array<A, D>);
+    fn mul_add_arrv_inplace<'b, 'c>(&mut self, a: ArrayView<'b, A, D>, b: ArrayView<'c, A, D>);
+}
+
+/// Same as `ArrayWithFloatBinaryMethods`, but for `ArrayWithFloatInplaceMethods`.
+pub trait ArrayWithFloatBinaryInplaceMethods<T, D, A = f64> : ArrayWithFloatInplaceMethods<D, A>
+where   D: Dimension,
+        A: Float,
+{
+    fn abs_sub_inplace(&mut self, other: T);
+    fn atan2_inplace(&mut self, other: T);
+    fn copysign_inplace(&mut self, other: T);
+    fn div_euclid_inplace(&mut self, other: T);
+    fn hypot_inplace(&mut self, other: T);
+    fn log_inplace(&mut self, other: T);
+    fn mul_add_inplace(&mut self, a: T, b: T);
+    fn powf_inplace(&mut self, other: T);
+    fn rem_euclid_inplace(&mut self, other: T);
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatInplaceMethods<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    #[duplicate_item(
+        __func_name__           __func__;
+        [ abs_inplace ]         [ abs ];
+        [ acos_inplace ]        [ acos ];
+        [ acosh_inplace ]       [ acosh ];
+        [ asin_inplace ]        [ asin ];
+        [ asinh_inplace ]       [ asinh ];
+        [ atan_inplace ]        [ atan ];
+        [ atanh_inplace ]       [ atanh ];
+        [ cbrt_inplace ]        [ cbrt ];
+        [ ceil_inplace ]        [ ceil ];
+        [ cos_inplace ]         [ cos ];
+        [ cosh_inplace ]        [ cosh ];
+        [ exp_inplace ]         [ exp ];
+        [ exp2_inplace ]        [ exp2 ];
+        [ exp_m1_inplace ]      [ exp_m1 ];
+        [ floor_inplace ]       [ floor ];
+        [ fract_inplace ]       [ fract ];
+        [ ln_inplace ]          [ ln ];
+        [ ln_1p_inplace ]       [ ln_1p ];
+        [ log10_inplace ]       [ log10 ];
+        [ log2_inplace ]        [ log2 ];
+        [ round_inplace ]       [ round ];
+        [ signum_inplace ]      [ signum ];
+        [ sin_inplace ]         [ sin ];
+        [ sinh_inplace ]        [ sinh ];
+        [ sqrt_inplace ]        [ sqrt ];
+        [ tan_inplace ]         [ tan ];
+        [ tanh_inplace ]        [ tanh ];
+        [ trunc_inplace ]       [ trunc ];
+    )]
+    fn __func_name__(&mut self) {
+        self.map_inplace(|num| *num = num.__func__());
+    }
+    fn powi_inplace(&mut self, n: i32) {
+        self.map_inplace(|num| *num = num.powi(n));
+    }
+    fn abs_sub_f64_inplace(&mut self, other: A) {
+        self.map_inplace(|num| *num = (*num - other).abs());
+    }
+    #[duplicate_item(
+        __func_name__               __func__;
+        [ atan2_f64_inplace ]       [ A::atan2 ];
+        [ copysign_f64_inplace ]    [ A::copysign ];
+        [ div_euclid_f64_inplace ]  [ div_euclid ];
+        [ hypot_f64_inplace ]       [ A::hypot ];
+        [ log_f64_inplace ]         [ A::log ];
+        [ powf_f64_inplace ]        [ A::powf ];
+        [ rem_euclid_f64_inplace ]  [ rem_euclid ];
+    )]
+    fn __func_name__(&mut self, other: A) {
+        self.map_inplace(|num| *num = __func__(*num, other));
+    }
+    #[duplicate_item(
+        __func_name__               __func_arrv__;
+        [ abs_sub_arr_inplace ]     [ abs_sub_arrv_inplace ];
+        [ atan2_arr_inplace ]       [ atan2_arrv_inplace ];
+        [ copysign_arr_inplace ]    [ copysign_arrv_inplace ];
+        [ div_euclid_arr_inplace ]  [ div_euclid_arrv_inplace ];
+        [ hypot_arr_inplace ]       [ hypot_arrv_inplace ];
+        [ log_arr_inplace ]         [ log_arrv_inplace ];
+        [ powf_arr_inplace ]        [ powf_arrv_inplace ];
+        [ rem_euclid_arr_inplace ]  [ rem_euclid_arrv_inplace ];
+    )]
+    fn __func_name__(&mut self, other: Array<A, D>) {
+        self.__func_arrv__(other.view());
+    }
+    fn abs_sub_arrv_inplace<'b>(&mut self, other: ArrayView<'b, A, D>) {
+        let other = broadcast_onto(self, &other);
+
+        Zip::from(self)
+            .and(other)
+            .for_each(|x, y| *x = (*x - *y).abs());
+    }
+    #[duplicate_item(
+        __func_name__               __func__;
+        [ atan2_arrv_inplace ]      [ A::atan2 ];
+        [ copysign_arrv_inplace ]   [ A::copysign ];
+        [ div_euclid_arrv_inplace ] [ div_euclid ];
+        [ hypot_arrv_inplace ]      [ A::hypot ];
+        [ log_arrv_inplace ]        [ A::log ];
+        [ powf_arrv_inplace ]       [ A::powf ];
+        [ rem_euclid_arrv_inplace ] [ rem_euclid ];
+    )]
+    fn __func_name__<'b>(&mut self, other: ArrayView<'b, A, D>) {
+        let other = broadcast_onto(self, &other);
+
+        Zip::from(self)
+            .and(other)
+            .for_each(|x, y| *x = __func__(*x, *y));
+    }
+    fn mul_add_f64_inplace(&mut self, a: A, b: A) {
+        self.map_inplace(|num| *num = num.mul_add(a, b));
+    }
+    fn mul_add_arr_inplace(&mut self, a: Array<A, D>, b: Array<A, D>) {
+        self.mul_add_arrv_inplace(a.view(), b.view());
+    }
+    fn mul_add_arrv_inplace<'b, 'c>(&mut self, a: ArrayView<'b, A, D>, b: ArrayView<'c, A, D>) {
+        let a = broadcast_onto(self, &a);
+        let b = broadcast_onto(self, &b);
+
+        Zip::from(self)
+            .and(a)
+            .and(b)
+            .for_each(|x, a, b| *x = x.mul_add(*a, *b));
+    }
+}
+
+#[duplicate_item(
+    __rhs_type__            __abs_sub__                 __atan2__               __copysign__                __div_euclid__                  __hypot__               __log__                 __mul_add__                 __powf__                __rem_euclid__;
+    [ A ]                   [ abs_sub_f64_inplace ]     [ atan2_f64_inplace ]   [ copysign_f64_inplace ]    [ div_euclid_f64_inplace ]      [ hypot_f64_inplace ]   [ log_f64_inplace ]     [ mul_add_f64_inplace ]     [ powf_f64_inplace ]    [ rem_euclid_f64_inplace ];
+    [ Array<A, D> ]         [ abs_sub_arr_inplace ]     [ atan2_arr_inplace ]   [ copysign_arr_inplace ]    [ div_euclid_arr_inplace ]      [ hypot_arr_inplace ]   [ log_arr_inplace ]     [ mul_add_arr_inplace ]     [ powf_arr_inplace ]    [ rem_euclid_arr_inplace ];
+    [ ArrayView<'_, A, D> ] [ abs_sub_arrv_inplace ]    [ atan2_arrv_inplace ]  [ copysign_arrv_inplace ]   [ div_euclid_arrv_inplace ]     [ hypot_arrv_inplace ]  [ log_arrv_inplace ]    [ mul_add_arrv_inplace ]    [ powf_arrv_inplace ]   [ rem_euclid_arrv_inplace ];
+)]
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatBinaryInplaceMethods<__rhs_type__, D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    #[duplicate_item(
+        __func_name__               __func__;
+        [ abs_sub_inplace ]         [ __abs_sub__ ];
+        [ atan2_inplace ]           [ __atan2__ ];
+        [ copysign_inplace ]        [ __copysign__ ];
+        [ div_euclid_inplace ]      [ __div_euclid__ ];
+        [ hypot_inplace ]           [ __hypot__ ];
+        [ log_inplace ]             [ __log__ ];
+        [ powf_inplace ]            [ __powf__ ];
+        [ rem_euclid_inplace ]      [ __rem_euclid__ ];
+    )]
+    fn __func_name__(&mut self, other: __rhs_type__) {
+        self.__func__(other);
+    }
+    fn mul_add_inplace(&mut self, a: __rhs_type__, b: __rhs_type__) {
+        self.__mul_add__(a, b);
+    }
+}
+
+// =====================================================================================
+
+/// Rayon-parallel counterparts of `ArrayWithFloatMethods`, prefixed with `par_`.
+///
+/// Arrays with fewer elements than `parallel_threshold()` are processed serially
+/// instead, as the overhead of parallelism would outweigh its gain.
+///
+/// Binary methods are available with a scalar (`_f64`) or an array view (`_arrv`)
+/// as `other`; array views are broadcast against `self` as with `ArrayWithFloatMethods`.
+pub trait ArrayWithFloatParallelMethods<D, A = f64>: ArrayWithFloatMethods<D, A>
+where
+    D: Dimension,
+    A: Float + Send + Sync,
+{
+    #[duplicate_item(
+        __func_name__;
+        [ par_abs ];
+        [ par_acos ];
+        [ par_acosh ];
+        [ par_asin ];
+        [ par_asinh ];
+        [ par_atan ];
+        [ par_atanh ];
+        [ par_cbrt ];
+        [ par_ceil ];
+        [ par_cos ];
+        [ par_cosh ];
+        [ par_exp ];
+        [ par_exp2 ];
+        [ par_exp_m1 ];
+        [ par_floor ];
+        [ par_fract ];
+        [ par_ln ];
+        [ par_ln_1p ];
+        [ par_log10 ];
+        [ par_log2 ];
+        [ par_round ];
+        [ par_signum ];
+        [ par_sin ];
+        [ par_sinh ];
+        [ par_sqrt ];
+        [ par_tan ];
+        [ par_tanh ];
+        [ par_trunc ];
+    )]
+    fn __func_name__(&self) -> Array<A, D>;
+
+    fn par_powi(&self, n: i32) -> Array<A, D>;
+    fn par_sin_cos(&self) -> Array<(A, A), D>;
+
+    #[duplicate_item(
+        __func_name__;
+        [ par_abs_sub_f64 ];
+        [ par_atan2_f64 ];
+        [ par_copysign_f64 ];
+        [ par_div_euclid_f64 ];
+        [ par_hypot_f64 ];
+        [ par_log_f64 ];
+        [ par_powf_f64 ];
+        [ par_rem_euclid_f64 ];
+    )]
+    fn __func_name__(&self, other: A) -> Array<A, D>;
+    #[duplicate_item(
+        __func_name__;
+        [ par_abs_sub_arrv ];
+        [ par_atan2_arrv ];
+        [ par_copysign_arrv ];
+        [ par_div_euclid_arrv ];
+        [ par_hypot_arrv ];
+        [ par_log_arrv ];
+        [ par_powf_arrv ];
+        [ par_rem_euclid_arrv ];
+    )]
+    fn __func_name__<'b>(&self, other: ArrayView<'b, A, D>) -> Array<A, D>;
+
+    fn par_mul_add_f64(&self, a: A, b: A) -> Array<A, D>;
+    fn par_mul_add_arrv<'b, 'c>(&self, a: ArrayView<'b, A, D>, b: ArrayView<'c, A, D>) -> Array<A, D>;
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatParallelMethods<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float + Send + Sync,
+{
+    #[duplicate_item(
+        __func_name__       __func__;
+        [ par_abs ]         [ abs ];
+        [ par_acos ]        [ acos ];
+        [ par_acosh ]       [ acosh ];
+        [ par_asin ]        [ asin ];
+        [ par_asinh ]       [ asinh ];
+        [ par_atan ]        [ atan ];
+        [ par_atanh ]       [ atanh ];
+        [ par_cbrt ]        [ cbrt ];
+        [ par_ceil ]        [ ceil ];
+        [ par_cos ]         [ cos ];
+        [ par_cosh ]        [ cosh ];
+        [ par_exp ]         [ exp ];
+        [ par_exp2 ]        [ exp2 ];
+        [ par_exp_m1 ]      [ exp_m1 ];
+        [ par_floor ]       [ floor ];
+        [ par_fract ]       [ fract ];
+        [ par_ln ]          [ ln ];
+        [ par_ln_1p ]       [ ln_1p ];
+        [ par_log10 ]       [ log10 ];
+        [ par_log2 ]        [ log2 ];
+        [ par_round ]       [ round ];
+        [ par_signum ]      [ signum ];
+        [ par_sin ]         [ sin ];
+        [ par_sinh ]        [ sinh ];
+        [ par_sqrt ]        [ sqrt ];
+        [ par_tan ]         [ tan ];
+        [ par_tanh ]        [ tanh ];
+        [ par_trunc ]       [ trunc ];
+    )]
+    fn __func_name__(&self) -> Array<A, D> {
+        if !use_parallel(self.len()) {
+            return ArrayWithFloatMethods::__func__(self);
+        }
+
+        return Zip::from(self).par_map_collect(|num| num.__func__());
+    }
+    fn par_powi(&self, n: i32) -> Array<A, D> {
+        if !use_parallel(self.len()) {
+            return self.powi(n);
+        }
+
+        return Zip::from(self).par_map_collect(|num| num.powi(n));
+    }
+    fn par_sin_cos(&self) -> Array<(A, A), D> {
+        if !use_parallel(self.len()) {
+            return self.sin_cos();
+        }
+
+        return Zip::from(self).par_map_collect(|num| num.sin_cos());
+    }
+    #[duplicate_item(
+        __func_name__           __func_f64__        __func__;
+        [ par_abs_sub_f64 ]     [ abs_sub_f64 ]     [ abs_sub ];
+        [ par_atan2_f64 ]       [ atan2_f64 ]       [ A::atan2 ];
+        [ par_copysign_f64 ]    [ copysign_f64 ]    [ A::copysign ];
+        [ par_div_euclid_f64 ]  [ div_euclid_f64 ]  [ div_euclid ];
+        [ par_hypot_f64 ]       [ hypot_f64 ]       [ A::hypot ];
+        [ par_log_f64 ]         [ log_f64 ]         [ A::log ];
+        [ par_powf_f64 ]        [ powf_f64 ]        [ A::powf ];
+        [ par_rem_euclid_f64 ]  [ rem_euclid_f64 ]  [ rem_euclid ];
+    )]
+    fn __func_name__(&self, other: A) -> Array<A, D> {
+        if !use_parallel(self.len()) {
+            return self.__func_f64__(other);
+        }
+
+        return Zip::from(self).par_map_collect(|num| __func__(*num, other));
+    }
+    #[duplicate_item(
+        __func_name__           __func_arrv__       __func__;
+        [ par_abs_sub_arrv ]    [ abs_sub_arrv ]    [ abs_sub ];
+        [ par_atan2_arrv ]      [ atan2_arrv ]      [ A::atan2 ];
+        [ par_copysign_arrv ]   [ copysign_arrv ]   [ A::copysign ];
+        [ par_div_euclid_arrv ] [ div_euclid_arrv ] [ div_euclid ];
+        [ par_hypot_arrv ]      [ hypot_arrv ]      [ A::hypot ];
+        [ par_log_arrv ]        [ log_arrv ]        [ A::log ];
+        [ par_powf_arrv ]       [ powf_arrv ]       [ A::powf ];
+        [ par_rem_euclid_arrv ] [ rem_euclid_arrv ] [ rem_euclid ];
+    )]
+    fn __func_name__<'b>(&self, other: ArrayView<'b, A, D>) -> Array<A, D> {
+        let (x, y) = broadcast_pair(self, &other);
+
+        if !use_parallel(x.len()) {
+            return self.__func_arrv__(other);
+        }
+
+        return Zip::from(x)
+                    .and(y)
+                    .par_map_collect(
+                        move |x, y| __func__(*x, *y)
+                    );
+    }
+    fn par_mul_add_f64(&self, a: A, b: A) -> Array<A, D> {
+        if !use_parallel(self.len()) {
+            return self.mul_add_f64(a, b);
+        }
+
+        return Zip::from(self).par_map_collect(|num| num.mul_add(a, b));
+    }
+    fn par_mul_add_arrv<'b, 'c>(&self, a: ArrayView<'b, A, D>, b: ArrayView<'c, A, D>) -> Array<A, D> {
+        let (x, a) = broadcast_pair(self, &a);
+        let (x, b) = broadcast_pair(&x, &b);
+
+        if !use_parallel(x.len()) {
+            return Zip::from(&x)
+                        .and(a.broadcast(x.raw_dim()).unwrap())
+                        .and(b)
+                        .map_collect(
+                            move |x, a, b| x.mul_add(*a, *b)
+                        );
+        }
+
+        return Zip::from(&x)
+                    .and(a.broadcast(x.raw_dim()).unwrap())
+                    .and(b)
+                    .par_map_collect(
+                        move |x, a, b| x.mul_add(*a, *b)
+                    );
+    }
+}
+
+// =====================================================================================
+
+/// PartialOrd comparison mimicking std::cmp::PartialOrd.
+///
+/// This does not however implement the actual trait, since that trait expects `bool`
+/// as returns but we are expecting `BoolArray<d>` instead.
+///
+/// Like `f64::partial_cmp`, any comparison involving NaN is `None` or `false`, apart
+/// from `not_equal` which is `true`.
+///
+/// The elementwise equality methods are named `equal` and `not_equal` after their numpy
+/// counterparts, since `eq` and `ne` would clash with `PartialEq`, which `ArrayBase`
+/// implements already.
+///
+/// The `_array` methods compare `self` with another array or array view elementwise,
+/// broadcasting them against each other. `other` may have fewer dimensions than `self`,
+/// e.g. an `F64Array1` of `[lat, lng]` compared against each row of an `F64LatLngArray`;
+/// the resultant `BoolArray<D>` is always of the dimension of `self`.
+pub trait ArrayWithFloatPartialOrd<D, A = f64> : ArrayWithFloatMethods<D, A>
+where   D: Dimension,
+        A: Float,
+{
+    fn partial_cmp(&self, other: &A) -> Array<Option<Ordering>, D>;
+
+    fn lt(&self, other: &A) -> BoolArray<D>;
+    fn le(&self, other: &A) -> BoolArray<D>;
+    fn gt(&self, other: &A) -> BoolArray<D>;
+    fn ge(&self, other: &A) -> BoolArray<D>;
+    fn equal(&self, other: &A) -> BoolArray<D>;
+    fn not_equal(&self, other: &A) -> BoolArray<D>;
+
+    fn partial_cmp_array<S, E>(&self, other: &ArrayBase<S, E>) -> Array<Option<Ordering>, D>
+    where   S: Data<Elem = A>,
+            E: Dimension;
+
+    #[duplicate_item(
+        __func_name__;
+        [ lt_array ];
+        [ le_array ];
+        [ gt_array ];
+        [ ge_array ];
+        [ equal_array ];
+        [ not_equal_array ];
+    )]
+    fn __func_name__<S, E>(&self, other: &ArrayBase<S, E>) -> BoolArray<D>
+    where   S: Data<Elem = A>,
+            E: Dimension;
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatPartialOrd<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    fn partial_cmp(&self, other: &A) -> Array<Option<Ordering>, D>{
+        return self.map(
+            |value| value.partial_cmp(other)
+        )
+    }
+
+    #[duplicate_item(
+        PartialOrdMethod    __op__;
+        [ lt ]              [ value < other ];
+        [ le ]              [ value <= other ];
+        [ gt ]              [ value > other ];
+        [ ge ]              [ value >= other ];
+        [ equal ]           [ value == other ];
+        [ not_equal ]       [ value != other ];
+    )]
+    fn PartialOrdMethod(&self, other: &A) -> BoolArray<D> {
+        return self.map(
+            |value| __op__
+        )
+    }
+
+    fn partial_cmp_array<S, E>(&self, other: &ArrayBase<S, E>) -> Array<Option<Ordering>, D>
+    where   S: Data<Elem = A>,
+            E: Dimension,
+    {
+        let (x, y) = broadcast_pair(self, other);
+
+        return Zip::from(x)
+                    .and(y)
+                    .map_collect(
+                        |value, other| value.partial_cmp(other)
+                    );
+    }
+
+    #[duplicate_item(
+        __func_name__           __op__;
+        [ lt_array ]            [ value < other ];
+        [ le_array ]            [ value <= other ];
+        [ gt_array ]            [ value > other ];
+        [ ge_array ]            [ value >= other ];
+        [ equal_array ]         [ value == other ];
+        [ not_equal_array ]     [ value != other ];
+    )]
+    fn __func_name__<S, E>(&self, other: &ArrayBase<S, E>) -> BoolArray<D>
+    where   S: Data<Elem = A>,
+            E: Dimension,
+    {
+        let (x, y) = broadcast_pair(self, other);
+
+        return Zip::from(x)
+                    .and(y)
+                    .map_collect(
+                        |value, other| __op__
+                    );
+    }
+}
+
+// =====================================================================================
+
+/// Default relative tolerance of `ArrayWithFloatApproxEq`, same as numpy's `isclose`.
+pub const DEFAULT_RTOL: f64 = 1e-5;
+/// Default absolute tolerance of `ArrayWithFloatApproxEq`, same as numpy's `isclose`.
+pub const DEFAULT_ATOL: f64 = 1e-8;
+
+/// Whether `a` and `b` are equal within tolerance, following numpy's `isclose`.
+fn is_close<A>(a: A, b: A, rtol: A, atol: A, equal_nan: bool) -> bool
+where   A: Float {
+    return match (a, b) {
+        // Also catches infinities of the same sign.
+        (a, b) if a == b => true,
+        (a, b) if a.is_nan() || b.is_nan() => equal_nan && a.is_nan() && b.is_nan(),
+        (a, b) if a.is_infinite() || b.is_infinite() => false,
+        (a, b) => (a - b).abs() <= atol + rtol * b.abs(),
+    };
+}
+
+/// Approximate equality between arrays, modelled on numpy's `isclose` and `allclose`.
+///
+/// Two elements `a` and `b` are considered equal if `|a - b| <= atol + rtol * |b|`;
+/// note that like numpy, this is not symmetric. Infinities are only equal to infinities
+/// of the same sign, and NaNs are only equal to each other if `equal_nan` is `true`.
+///
+/// `rtol` and `atol` default to `DEFAULT_RTOL` and `DEFAULT_ATOL`, and `equal_nan` to
+/// `false`, if `None` is given. `other` is broadcast against `self`.
+pub trait ArrayWithFloatApproxEq<D, A = f64> : ArrayWithFloatMethods<D, A>
+where   D: Dimension,
+        A: Float,
+{
+    fn is_close<S, E>(
+        &self,
+        other: &ArrayBase<S, E>,
+        rtol: Option<A>,
+        atol: Option<A>,
+        equal_nan: Option<bool>,
+    ) -> BoolArray<D>
+    where   S: Data<Elem = A>,
+            E: Dimension;
+
+    fn all_close<S, E>(
+        &self,
+        other: &ArrayBase<S, E>,
+        rtol: Option<A>,
+        atol: Option<A>,
+        equal_nan: Option<bool>,
+    ) -> bool
+    where   S: Data<Elem = A>,
+            E: Dimension;
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatApproxEq<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    fn is_close<S, E>(
+        &self,
+        other: &ArrayBase<S, E>,
+        rtol: Option<A>,
+        atol: Option<A>,
+        equal_nan: Option<bool>,
+    ) -> BoolArray<D>
+    where   S: Data<Elem = A>,
+            E: Dimension,
+    {
+        let rtol = rtol.unwrap_or_else(|| A::from(DEFAULT_RTOL).unwrap());
+        let atol = atol.unwrap_or_else(|| A::from(DEFAULT_ATOL).unwrap());
+        let equal_nan = equal_nan.unwrap_or(false);
+
+        let (x, y) = broadcast_pair(self, other);
+
+        return Zip::from(x)
+                    .and(y)
+                    .map_collect(
+                        |a, b| is_close(*a, *b, rtol, atol, equal_nan)
+                    );
+    }
+
+    fn all_close<S, E>(
+        &self,
+        other: &ArrayBase<S, E>,
+        rtol: Option<A>,
+        atol: Option<A>,
+        equal_nan: Option<bool>,
+    ) -> bool
+    where   S: Data<Elem = A>,
+            E: Dimension,
+    {
+        let rtol = rtol.unwrap_or_else(|| A::from(DEFAULT_RTOL).unwrap());
+        let atol = atol.unwrap_or_else(|| A::from(DEFAULT_ATOL).unwrap());
+        let equal_nan = equal_nan.unwrap_or(false);
+
+        let (x, y) = broadcast_pair(self, other);
+
+        // Short circuited.
+        return Zip::from(x)
+                    .and(y)
+                    .all(
+                        |a, b| is_close(*a, *b, rtol, atol, equal_nan)
+                    );
+    }
+}
+
+// =====================================================================================
+
+/// Additional Trait for Arrays containing angular float data.
+/// 
+pub trait ArrayWithFloatAngularMethods<D, A = f64> : ArrayWithFloatMethods<D, A>
+where
+    D: Dimension,
+    A: Float,
+{
+    fn to_rad(&self) -> Array<A, D>;
+    fn to_dec(&self) -> Array<A, D>;
+}
+
+/// Implements `Float` angular conversion methods for ArrayBase.
+/// 
+/// This `impl` is trait bound to `Float` Arrays only.
+/// use duplicate::duplicate_item;
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatAngularMethods<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    fn to_rad(&self) -> Array<A, D> {
+        let (pi, half_turn) = (A::from(f64::consts::PI).unwrap(), A::from(180.).unwrap());
+
+        return self.map(|num| *num * pi / half_turn);
+    }
+
+    fn to_dec(&self) -> Array<A, D> {
+        let (pi, half_turn) = (A::from(f64::consts::PI).unwrap(), A::from(180.).unwrap());
+
+        return self.map(|num| *num * half_turn / pi);
+    }
+}
+
+// =====================================================================================
+
+/// Additional Trait for Arrays containing latitude-longitude float data.
+/// 
+/// Only allow for arrays with 2 dimensions.
+pub trait ArrayWithFloatLatLngMethods<A = f64> : ArrayWithFloatAngularMethods<Ix2, A>
+where   A: Float,
+{
+    fn normalize(&mut self);
+}
+
+/// Implements `Float` latitude-longitude conversion methods for ArrayBase.
+/// 
+/// This `impl` is trait bound to `Float` Arrays only.
+/// use duplicate::duplicate_item;
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array2<A> ]                       [ A ];
+    [ ArcArray2<A> ]                    [ A ];
+    // [ ArrayView2<'a, A> ]               [ 'a, A ];
+    [ ArrayViewMut2<'a, A> ]            [ 'a, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatLatLngMethods<A>
+for __array_type__
+where   A: Float,
+{
+    fn normalize(&mut self) {
+        let [quarter_turn, half_turn, full_turn] = [90., 180., 360.].map(
+            |value| A::from(value).unwrap()
+        );
+
+        return self.axis_iter_mut(Axis(0)).for_each(
+            | mut latlng | {
+                let mut lat = latlng[0] % full_turn;
+                let mut lng = latlng[1] % full_turn;
+
+                if lat > half_turn {lat = lat - full_turn}
+                if lat < -half_turn {lat = lat + full_turn}
+                if lat > quarter_turn {
+                    lat = half_turn - lat;
+                    lng = lng + half_turn;
+                }
+                if lat < -quarter_turn {
+                    lat = -half_turn - lat;
+                    lng = lng + half_turn;
+                }
+
+                match lng {
+                    value if value > half_turn => lng = lng - full_turn,
+                    value if value < -half_turn => lng = lng + full_turn,
+                    _ => {},
+                };
+
+                latlng[0] = lat;
+                latlng[1] = lng;
+            }
+        );
+    }
+}
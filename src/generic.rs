@@ -17,7 +17,9 @@ use ndarray::{
     RawData,
     Slice,
     SliceArg,
+    Zip,
 };
+use num_traits::Zero;
 
 use ndarray::iter::{
     Lanes,
@@ -147,4 +149,121 @@ impl<A> ArrayFromDuplicatedRows<A> for __array_type__<A> {
 
         return result;
     }
-}
\ No newline at end of file
+}
+
+/// Broadcast two arrays against each other, following the numpy broadcasting rules.
+///
+/// Shapes are aligned from their trailing axes; any axis of length 1 is stretched to
+/// match the other side. `rhs` must not have more dimensions than `lhs`, so that the
+/// resultant views share the dimension type `D` of `lhs`.
+///
+/// Panics if the two shapes are not broadcast compatible.
+pub(crate) fn broadcast_pair<'a, 'b, S, T, D, E>(
+    lhs: &'a ArrayBase<S, D>,
+    rhs: &'b ArrayBase<T, E>,
+) -> (ArrayView<'a, S::Elem, D>, ArrayView<'b, T::Elem, D>)
+where   S: Data,
+        T: Data,
+        D: Dimension,
+        E: Dimension,
+{
+    assert!(
+        rhs.ndim() <= lhs.ndim(),
+        "cannot broadcast array of shape {:?} onto array of shape {:?}.",
+        rhs.shape(),
+        lhs.shape(),
+    );
+
+    let offset = lhs.ndim() - rhs.ndim();
+    let mut shape = lhs.raw_dim();
+
+    shape.slice_mut()[offset..]
+         .iter_mut()
+         .zip(rhs.shape())
+         .for_each(
+            | (l, &r) | {
+                *l = match (*l, r) {
+                    (l, r) if l == r => l,
+                    (1, r) => r,
+                    (l, 1) => l,
+                    _ => panic!(
+                        "could not broadcast arrays of shapes {:?} and {:?} together.",
+                        lhs.shape(),
+                        rhs.shape(),
+                    ),
+                }
+            }
+         );
+
+    return (
+        lhs.broadcast(shape.clone()).unwrap(),
+        rhs.broadcast(shape).unwrap(),
+    );
+}
+
+/// Broadcast `rhs` onto the shape of `lhs`, for operations where the shape of `lhs`
+/// cannot change, e.g. in-place ones.
+///
+/// Panics if `rhs` cannot be broadcast to the shape of `lhs`.
+pub(crate) fn broadcast_onto<'b, S, T, D, E>(
+    lhs: &ArrayBase<S, D>,
+    rhs: &'b ArrayBase<T, E>,
+) -> ArrayView<'b, T::Elem, D>
+where   S: RawData,
+        T: Data,
+        D: Dimension,
+        E: Dimension,
+{
+    return {
+        rhs
+        .broadcast(lhs.raw_dim())
+        .unwrap_or_else(
+            || panic!(
+                "cannot broadcast array of shape {:?} onto array of shape {:?}.",
+                rhs.shape(),
+                lhs.shape(),
+            )
+        )
+    };
+}
+
+/// Apply `func` to every lane of `array` along `axis`, each returning a new lane of the
+/// same length.
+pub(crate) fn map_lanes<A, B, D, F>(array: ArrayView<A, D>, axis: Axis, func: F) -> Array<B, D>
+where   D: Dimension,
+        B: Clone + Zero,
+        F: Fn(ArrayView1<A>) -> Vec<B>,
+{
+    let mut result = Array::zeros(array.raw_dim());
+
+    Zip::from(result.lanes_mut(axis))
+        .and(array.lanes(axis))
+        .for_each(
+            | mut result_lane, lane | {
+                result_lane.assign(&ArrayView1::from(&func(lane)))
+            }
+        );
+
+    return result;
+}
+
+/// Index of the element at `offset` in the row-major order of an array of shape
+/// `shape`.
+pub(crate) fn unravel_index<D>(mut offset: usize, shape: &D) -> D
+where   D: Dimension,
+{
+    let mut index = shape.clone();
+
+    index.slice_mut()
+         .iter_mut()
+         .zip(shape.slice())
+         .rev()
+         .for_each(
+            | (i, &len) | {
+                *i = offset % len;
+                offset /= len;
+            }
+         );
+
+    return index;
+}
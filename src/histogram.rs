@@ -0,0 +1,290 @@
+use duplicate::duplicate_item;
+use ndarray::{
+    ArcArray,
+    ArcArray2,
+    Array,
+    Array1,
+    Array2,
+    ArrayBase,
+    ArrayView,
+    ArrayView1,
+    ArrayView2,
+    ArrayViewMut,
+    ArrayViewMut2,
+    Axis,
+    Data,
+    Dimension,
+    Ix1,
+    Zip,
+};
+use num_traits::Float;
+
+use super::floatarray::{
+    ArrayWithFloatMethods,
+};
+
+/// Bins of a histogram.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HistogramBins<A = f64> {
+    /// This many bins of equal width over the range.
+    Count(usize),
+    /// Bins between these increasing edges; the range is ignored.
+    Edges(Array1<A>),
+}
+
+/// Edges of `bins` for `values`; for [`HistogramBins::Count`], spread evenly over
+/// `range`, which defaults to the extent of the non-NaN `values`.
+fn edges_of<'a, A, I>(values: I, bins: HistogramBins<A>, range: Option<(A, A)>) -> Array1<A>
+where   A: Float + 'a,
+        I: Iterator<Item = &'a A>,
+{
+    return match bins {
+        HistogramBins::Count(count) => {
+            assert!(count > 0, "There must be at least 1 bin.");
+
+            let (min, max) = range.unwrap_or_else(
+                || values.filter(|value| !value.is_nan())
+                         .fold(
+                            None,
+                            | extent, value | match extent {
+                                None => Some((*value, *value)),
+                                Some((min, max)) => Some((value.min(min), value.max(max))),
+                            }
+                         )
+                         .unwrap_or((A::zero(), A::one()))
+            );
+            assert!(min <= max, "The lower end of range must not be greater than the upper end.");
+
+            let half = A::from(0.5).unwrap();
+            let (min, max) = if min == max { (min - half, max + half) } else { (min, max) };
+
+            Array1::linspace(min, max, count + 1)
+        },
+        HistogramBins::Edges(edges) => {
+            assert!(edges.len() >= 2, "There must be at least 2 edges.");
+            assert!(
+                edges.windows(2).into_iter().all(|pair| pair[0] < pair[1]),
+                "Edges must be strictly increasing."
+            );
+
+            // Contiguous, for `bin_of`.
+            edges.into_iter().collect()
+        },
+    };
+}
+
+/// Index of the bin between `edges` containing `value`, if any; the last bin includes
+/// its upper edge.
+fn bin_of<A>(edges: &Array1<A>, value: A) -> Option<usize>
+where   A: Float,
+{
+    let last = edges.len() - 1;
+
+    if value.is_nan() || value < edges[0] || value > edges[last] {
+        return None;
+    }
+
+    let edges = edges.as_slice().unwrap();
+
+    return Some(edges.partition_point(|edge| *edge <= value).min(last) - 1);
+}
+
+/// Normalise `counts` into a probability density over the bins between `edges`.
+fn density_of<A>(counts: Array1<A>, edges: &Array1<A>) -> Array1<A>
+where   A: Float,
+{
+    let total = counts.sum();
+
+    return Zip::from(&counts)
+               .and(edges.windows(2))
+               .map_collect(|count, pair| *count / total / (pair[1] - pair[0]));
+}
+
+/// Histograms of the values of an array, following `numpy.histogram`.
+///
+/// Each method returns the counts for each bin, together with the `bins + 1` edges of
+/// the bins. Bins include their lower edges, and the last bin its upper edge as well;
+/// values outside of the edges and NaNs are not counted.
+///
+/// With [`HistogramBins::Count`], the bins are of equal width over `range`, which
+/// defaults to the minimum and maximum non-NaN values; if these are equal, the range
+/// is widened by `0.5` on either side.
+pub trait ArrayWithFloatHistogramMethods<D, A = f64> : ArrayWithFloatMethods<D, A>
+where   D: Dimension,
+        A: Float,
+{
+    fn histogram(&self, bins: HistogramBins<A>, range: Option<(A, A)>) -> (Array1<usize>, Array1<A>);
+
+    /// The histogram normalised to a probability density; it integrates to `1` over
+    /// the range.
+    fn histogram_density(&self, bins: HistogramBins<A>, range: Option<(A, A)>) -> (Array1<A>, Array1<A>);
+
+    /// The histogram with each value counted by its corresponding element in
+    /// `weights`, which must be of the same shape as `self`; normalised to a
+    /// probability density if `density` is set.
+    fn histogram_weighted(
+        &self,
+        bins: HistogramBins<A>,
+        range: Option<(A, A)>,
+        weights: ArrayView<'_, A, D>,
+        density: Option<bool>,
+    ) -> (Array1<A>, Array1<A>);
+
+    /// Indices of the bins between `edges` each element falls into, following
+    /// `numpy.digitize`.
+    ///
+    /// `edges` must be increasing. Each element `x` is given the index `i` such that
+    /// `edges[i - 1] <= x < edges[i]`, or `edges[i - 1] < x <= edges[i]` if `right`
+    /// is set; so values below all edges are given `0`, and values above all edges,
+    /// and NaNs, are given `edges.len()`.
+    fn digitize<S>(&self, edges: &ArrayBase<S, Ix1>, right: Option<bool>) -> Array<usize, D>
+    where   S: Data<Elem = A>;
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatHistogramMethods<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    fn histogram(&self, bins: HistogramBins<A>, range: Option<(A, A)>) -> (Array1<usize>, Array1<A>) {
+        let edges = edges_of(self.iter(), bins, range);
+        let mut counts = Array1::zeros(edges.len() - 1);
+
+        self.iter()
+            .filter_map(|value| bin_of(&edges, *value))
+            .for_each(|bin| counts[bin] += 1);
+
+        return (counts, edges);
+    }
+    fn histogram_density(&self, bins: HistogramBins<A>, range: Option<(A, A)>) -> (Array1<A>, Array1<A>) {
+        let (counts, edges) = self.histogram(bins, range);
+
+        return (density_of(counts.mapv(|count| A::from(count).unwrap()), &edges), edges);
+    }
+    fn histogram_weighted(
+        &self,
+        bins: HistogramBins<A>,
+        range: Option<(A, A)>,
+        weights: ArrayView<'_, A, D>,
+        density: Option<bool>,
+    ) -> (Array1<A>, Array1<A>) {
+        assert_eq!(
+            weights.shape(), self.shape(),
+            "weights must be of the same shape as the array."
+        );
+
+        let edges = edges_of(self.iter(), bins, range);
+        let mut counts = Array1::zeros(edges.len() - 1);
+
+        self.iter()
+            .zip(weights.iter())
+            .filter_map(|(value, weight)| bin_of(&edges, *value).map(|bin| (bin, *weight)))
+            .for_each(|(bin, weight)| counts[bin] = counts[bin] + weight);
+
+        return match density.unwrap_or(false) {
+            true => (density_of(counts, &edges), edges),
+            false => (counts, edges),
+        };
+    }
+    fn digitize<S>(&self, edges: &ArrayBase<S, Ix1>, right: Option<bool>) -> Array<usize, D>
+    where   S: Data<Elem = A>,
+    {
+        let right = right.unwrap_or(false);
+        let edges = edges.to_vec();
+
+        return self.mapv(
+            | value | match value.is_nan() {
+                true => edges.len(),
+                false => edges.partition_point(|edge| if right { *edge < value } else { *edge <= value }),
+            }
+        );
+    }
+}
+
+/// 2-dimensional histograms of `(lat, lng)` point clouds, such as `F64LatLngArray`s,
+/// following `numpy.histogram2d`.
+///
+/// `bins` and `range` are given for latitudes and longitudes respectively, with the
+/// same meaning as for [`ArrayWithFloatHistogramMethods`]. Returns the counts of
+/// points in each bin, indexed by `(lat, lng)`, together with the edges of the
+/// latitude and longitude bins.
+///
+/// Each point is counted by its corresponding element in `weights` if given, and the
+/// counts are normalised to a probability density over the area if `density` is set.
+/// Points with a NaN coordinate, or outside of the edges, are not counted.
+pub trait ArrayWithFloatLatLngHistogramMethods<A = f64>
+where   A: Float,
+{
+    fn histogram2d(
+        &self,
+        bins: (HistogramBins<A>, HistogramBins<A>),
+        range: Option<((A, A), (A, A))>,
+        weights: Option<ArrayView1<'_, A>>,
+        density: Option<bool>,
+    ) -> (Array2<A>, Array1<A>, Array1<A>);
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array2<A> ]                       [ A ];
+    [ ArcArray2<A> ]                    [ A ];
+    [ ArrayView2<'a, A> ]               [ 'a, A ];
+    [ ArrayViewMut2<'a, A> ]            [ 'a, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatLatLngHistogramMethods<A>
+for __array_type__
+where   A: Float,
+{
+    fn histogram2d(
+        &self,
+        bins: (HistogramBins<A>, HistogramBins<A>),
+        range: Option<((A, A), (A, A))>,
+        weights: Option<ArrayView1<'_, A>>,
+        density: Option<bool>,
+    ) -> (Array2<A>, Array1<A>, Array1<A>) {
+        assert_eq!(self.ncols(), 2, "histogram2d requires an array of shape (N, 2).");
+
+        if let Some(weights) = weights {
+            assert_eq!(weights.len(), self.nrows(), "weights must have one element per row.");
+        }
+
+        let lat_edges = edges_of(self.column(0).iter(), bins.0, range.map(|range| range.0));
+        let lng_edges = edges_of(self.column(1).iter(), bins.1, range.map(|range| range.1));
+
+        let mut counts = Array2::zeros((lat_edges.len() - 1, lng_edges.len() - 1));
+
+        self.axis_iter(Axis(0))
+            .enumerate()
+            .for_each(
+                | (index, latlng) | {
+                    if let (Some(lat), Some(lng)) = (bin_of(&lat_edges, latlng[0]), bin_of(&lng_edges, latlng[1])) {
+                        counts[(lat, lng)] = counts[(lat, lng)]
+                                             + weights.map_or(A::one(), |weights| weights[index]);
+                    }
+                }
+            );
+
+        if density.unwrap_or(false) {
+            let total = counts.sum();
+
+            Zip::indexed(&mut counts)
+                .for_each(
+                    | (lat, lng), count | {
+                        *count = *count
+                                 / total
+                                 / (lat_edges[lat + 1] - lat_edges[lat])
+                                 / (lng_edges[lng + 1] - lng_edges[lng])
+                    }
+                );
+        }
+
+        return (counts, lat_edges, lng_edges);
+    }
+}
@@ -0,0 +1,297 @@
+use duplicate::duplicate_item;
+use ndarray::{
+    ArcArray,
+    Array,
+    Array1,
+    ArrayView,
+    ArrayView1,
+    ArrayViewMut,
+    Axis,
+    Dimension,
+    RemoveAxis,
+    Zip,
+};
+use num_traits::Float;
+
+use super::floatarray::{
+    ArrayWithFloatMethods,
+};
+
+/// Trapezoidal rule over `lane`, where `spacing(index)` is the width of the interval
+/// following `lane[index]`.
+fn trapz<A, F>(lane: ArrayView1<A>, spacing: F) -> A
+where   A: Float,
+        F: Fn(usize) -> A,
+{
+    let two = A::from(2).unwrap();
+
+    return (1..lane.len()).fold(
+        A::zero(),
+        | sum, index | sum + (lane[index - 1] + lane[index]) / two * spacing(index - 1)
+    );
+}
+
+/// Running trapezoidal rule over `lane`, optionally preceded by `initial`.
+fn cumtrapz<A, F>(lane: ArrayView1<A>, spacing: F, initial: Option<A>) -> Vec<A>
+where   A: Float,
+        F: Fn(usize) -> A,
+{
+    let two = A::from(2).unwrap();
+    let mut sum = A::zero();
+
+    return initial.into_iter()
+                  .chain(
+                      (1..lane.len()).map(
+                          | index | {
+                              sum = sum + (lane[index - 1] + lane[index]) / two * spacing(index - 1);
+                              sum
+                          }
+                      )
+                  )
+                  .collect();
+}
+
+/// `cumtrapz` over every lane of `array` along `axis`.
+fn cumtrapz_axis<A, D, F>(array: ArrayView<A, D>, axis: Axis, spacing: F, initial: Option<A>) -> Array<A, D>
+where   A: Float,
+        D: Dimension,
+        F: Fn(usize) -> A,
+{
+    let mut dim = array.raw_dim();
+    dim[axis.index()] = array.len_of(axis).saturating_sub(1) + initial.iter().count();
+
+    let mut result = Array::zeros(dim);
+
+    Zip::from(result.lanes_mut(axis))
+        .and(array.lanes(axis))
+        .for_each(
+            | mut result_lane, lane | {
+                result_lane.assign(&ArrayView1::from(&cumtrapz(lane, &spacing, initial)))
+            }
+        );
+
+    return result;
+}
+
+/// Composite Simpson's rule over `lane`, following `scipy.integrate.simpson`.
+///
+/// With an odd number of intervals, the last one is integrated with Cartwright's
+/// correction; with only one interval, this falls back to the trapezoidal rule.
+fn simpson<A, F>(lane: ArrayView1<A>, spacing: F) -> A
+where   A: Float,
+        F: Fn(usize) -> A,
+{
+    let len = lane.len();
+
+    if len < 3 {
+        return trapz(lane, spacing);
+    }
+
+    let two = A::from(2).unwrap();
+    let three = A::from(3).unwrap();
+    let six = A::from(6).unwrap();
+
+    // Number of intervals integrated by pairs.
+    let paired = (len - 1) / 2 * 2;
+
+    let mut sum = (0..paired).step_by(2).fold(
+        A::zero(),
+        | sum, index | {
+            let h0 = spacing(index);
+            let h1 = spacing(index + 1);
+            let hsum = h0 + h1;
+
+            sum + hsum / six * (
+                lane[index] * (two - h1 / h0)
+                + lane[index + 1] * (hsum * hsum / (h0 * h1))
+                + lane[index + 2] * (two - h0 / h1)
+            )
+        }
+    );
+
+    if paired < len - 1 {
+        let h0 = spacing(len - 3);
+        let h1 = spacing(len - 2);
+
+        let alpha = (two * h1.powi(2) + three * h0 * h1) / (six * (h0 + h1));
+        let beta = (h1.powi(2) + three * h0 * h1) / (six * h0);
+        let eta = h1.powi(3) / (six * h0 * (h0 + h1));
+
+        sum = sum + alpha * lane[len - 1] + beta * lane[len - 2] - eta * lane[len - 3];
+    }
+
+    return sum;
+}
+
+/// Numerical integration along an axis.
+///
+/// The samples are either at a uniform spacing `dx` along `axis`, or at the
+/// coordinates `coords`, which must be as long as `axis`. Integrating fewer than 2
+/// samples gives `0`.
+///
+/// `cumtrapz_axis` returns the running integral, which is one shorter along `axis`
+/// than `self`, unless an `initial` value is given to prepend. As with
+/// `scipy.integrate.cumulative_trapezoid`, `initial` is only prepended; it is not
+/// added to the rest of the running integral.
+///
+/// `_f64`, `_arr` and `_arrv` variants accept the spacing as a scalar, an `Array1` and
+/// an `ArrayView1` respectively; see [`ArrayWithFloatSpacedIntegrationMethods`] for
+/// methods accepting all of them.
+pub trait ArrayWithFloatIntegrationMethods<D, A = f64> : ArrayWithFloatMethods<D, A>
+where   D: Dimension,
+        A: Float,
+{
+    #[duplicate_item(
+        __func_name__;
+        [ trapz_axis_f64 ];
+        [ simpson_axis_f64 ];
+    )]
+    fn __func_name__(&self, axis: Axis, dx: A) -> Array<A, D::Smaller>
+    where   D: RemoveAxis;
+    #[duplicate_item(
+        __func_name__;
+        [ trapz_axis_arr ];
+        [ simpson_axis_arr ];
+    )]
+    fn __func_name__(&self, axis: Axis, coords: Array1<A>) -> Array<A, D::Smaller>
+    where   D: RemoveAxis;
+    #[duplicate_item(
+        __func_name__;
+        [ trapz_axis_arrv ];
+        [ simpson_axis_arrv ];
+    )]
+    fn __func_name__<'b>(&self, axis: Axis, coords: ArrayView1<'b, A>) -> Array<A, D::Smaller>
+    where   D: RemoveAxis;
+
+    fn cumtrapz_axis_f64(&self, axis: Axis, dx: A, initial: Option<A>) -> Array<A, D>;
+    fn cumtrapz_axis_arr(&self, axis: Axis, coords: Array1<A>, initial: Option<A>) -> Array<A, D>;
+    fn cumtrapz_axis_arrv<'b>(
+        &self,
+        axis: Axis,
+        coords: ArrayView1<'b, A>,
+        initial: Option<A>,
+    ) -> Array<A, D>;
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatIntegrationMethods<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    #[duplicate_item(
+        __func_name__           __func__;
+        [ trapz_axis_f64 ]      [ trapz ];
+        [ simpson_axis_f64 ]    [ simpson ];
+    )]
+    fn __func_name__(&self, axis: Axis, dx: A) -> Array<A, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.map_axis(axis, |lane| __func__(lane, |_| dx));
+    }
+    #[duplicate_item(
+        __func_name__           __func_arrv__;
+        [ trapz_axis_arr ]      [ trapz_axis_arrv ];
+        [ simpson_axis_arr ]    [ simpson_axis_arrv ];
+    )]
+    fn __func_name__(&self, axis: Axis, coords: Array1<A>) -> Array<A, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.__func_arrv__(axis, coords.view());
+    }
+    #[duplicate_item(
+        __func_name__           __func__;
+        [ trapz_axis_arrv ]     [ trapz ];
+        [ simpson_axis_arrv ]   [ simpson ];
+    )]
+    fn __func_name__<'b>(&self, axis: Axis, coords: ArrayView1<'b, A>) -> Array<A, D::Smaller>
+    where   D: RemoveAxis
+    {
+        assert_eq!(
+            coords.len(), self.len_of(axis),
+            "coords must be as long as axis."
+        );
+
+        return self.map_axis(
+            axis,
+            |lane| __func__(lane, |index| coords[index + 1] - coords[index])
+        );
+    }
+
+    fn cumtrapz_axis_f64(&self, axis: Axis, dx: A, initial: Option<A>) -> Array<A, D> {
+        return cumtrapz_axis(self.view(), axis, |_| dx, initial);
+    }
+    fn cumtrapz_axis_arr(&self, axis: Axis, coords: Array1<A>, initial: Option<A>) -> Array<A, D> {
+        return self.cumtrapz_axis_arrv(axis, coords.view(), initial);
+    }
+    fn cumtrapz_axis_arrv<'b>(
+        &self,
+        axis: Axis,
+        coords: ArrayView1<'b, A>,
+        initial: Option<A>,
+    ) -> Array<A, D> {
+        assert_eq!(
+            coords.len(), self.len_of(axis),
+            "coords must be as long as axis."
+        );
+
+        return cumtrapz_axis(
+            self.view(),
+            axis,
+            |index| coords[index + 1] - coords[index],
+            initial,
+        );
+    }
+}
+
+/// Allow the integration methods to accept a scalar spacing, an `Array1` or an
+/// `ArrayView1` of coordinates alike.
+pub trait ArrayWithFloatSpacedIntegrationMethods<T, D, A = f64> : ArrayWithFloatIntegrationMethods<D, A>
+where   D: Dimension,
+        A: Float,
+{
+    fn trapz_axis(&self, axis: Axis, spacing: T) -> Array<A, D::Smaller>
+    where   D: RemoveAxis;
+    fn simpson_axis(&self, axis: Axis, spacing: T) -> Array<A, D::Smaller>
+    where   D: RemoveAxis;
+    fn cumtrapz_axis(&self, axis: Axis, spacing: T, initial: Option<A>) -> Array<A, D>;
+}
+
+#[duplicate_item(
+    __rhs_type__                __trapz__               __simpson__             __cumtrapz__;
+    [ A ]                       [ trapz_axis_f64 ]      [ simpson_axis_f64 ]    [ cumtrapz_axis_f64 ];
+    [ Array1<A> ]               [ trapz_axis_arr ]      [ simpson_axis_arr ]    [ cumtrapz_axis_arr ];
+    [ ArrayView1<'_, A> ]       [ trapz_axis_arrv ]     [ simpson_axis_arrv ]   [ cumtrapz_axis_arrv ];
+)]
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatSpacedIntegrationMethods<__rhs_type__, D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    fn trapz_axis(&self, axis: Axis, spacing: __rhs_type__) -> Array<A, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.__trapz__(axis, spacing);
+    }
+    fn simpson_axis(&self, axis: Axis, spacing: __rhs_type__) -> Array<A, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.__simpson__(axis, spacing);
+    }
+    fn cumtrapz_axis(&self, axis: Axis, spacing: __rhs_type__, initial: Option<A>) -> Array<A, D> {
+        return self.__cumtrapz__(axis, spacing, initial);
+    }
+}
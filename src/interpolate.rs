@@ -0,0 +1,196 @@
+use ndarray::{
+    Array,
+    Array1,
+    ArrayBase,
+    ArrayView1,
+    Data,
+    Dimension,
+};
+use num_traits::Float;
+
+/// Index `i` of the interval `[xp[i], xp[i + 1])` containing `x`, limited to the
+/// first and last intervals.
+fn interval_of<A>(xp: ArrayView1<A>, x: A) -> usize
+where   A: Float,
+{
+    let found = xp.as_slice()
+                  .map(|xp| xp.partition_point(|v| *v <= x))
+                  .unwrap_or_else(|| xp.iter().take_while(|v| **v <= x).count());
+
+    return found.saturating_sub(1).min(xp.len().saturating_sub(2));
+}
+
+/// Piecewise linear interpolation of the points `(xp, fp)` at `x`, following
+/// `numpy.interp`.
+///
+/// `xp` must be increasing; this is not checked. Values of `x` below `xp[0]` are
+/// given `left`, and values above the last `xp` are given `right`; these default to
+/// the first and last `fp` respectively. NaNs in `x` give NaN.
+///
+/// Panics if `xp` and `fp` are empty or of different lengths.
+pub fn interp<A, S, D>(
+    x: &ArrayBase<S, D>,
+    xp: ArrayView1<'_, A>,
+    fp: ArrayView1<'_, A>,
+    left: Option<A>,
+    right: Option<A>,
+) -> Array<A, D>
+where   A: Float,
+        S: Data<Elem = A>,
+        D: Dimension,
+{
+    assert!(!xp.is_empty(), "xp must not be empty.");
+    assert_eq!(xp.len(), fp.len(), "xp and fp must be of the same length.");
+
+    let last = xp.len() - 1;
+    let left = left.unwrap_or(fp[0]);
+    let right = right.unwrap_or(fp[last]);
+
+    return x.mapv(
+        | x | {
+            if x.is_nan() {
+                A::nan()
+            } else if x < xp[0] {
+                left
+            } else if x > xp[last] {
+                right
+            } else if x == xp[last] {
+                fp[last]
+            } else {
+                let index = interval_of(xp, x);
+                let fraction = (x - xp[index]) / (xp[index + 1] - xp[index]);
+
+                fp[index] + (fp[index + 1] - fp[index]) * fraction
+            }
+        }
+    );
+}
+
+/// Conditions at the two ends of a [`CubicSpline`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SplineBoundary<A = f64> {
+    /// Zero second derivative at both ends.
+    Natural,
+    /// The given first derivatives at the start and end respectively.
+    Clamped(A, A),
+}
+
+impl<A> Default for SplineBoundary<A> {
+    fn default() -> Self {
+        return Self::Natural;
+    }
+}
+
+/// A cubic spline interpolating a set of points `(x, y)`.
+///
+/// Query points outside of `x` are extrapolated using the polynomials of the first
+/// and last intervals.
+#[derive(Clone, Debug)]
+pub struct CubicSpline<A = f64> {
+    x: Array1<A>,
+    y: Array1<A>,
+
+    /// Second derivatives at each `x`.
+    m: Array1<A>,
+}
+
+impl<A> CubicSpline<A>
+where   A: Float,
+{
+    /// Fit a spline through `(x, y)`, with `boundary` defaulting to
+    /// [`SplineBoundary::Natural`].
+    ///
+    /// Panics if `x` and `y` are of different lengths, if there are fewer than 2
+    /// points, or if `x` is not strictly increasing.
+    pub fn new(
+        x: ArrayView1<'_, A>,
+        y: ArrayView1<'_, A>,
+        boundary: Option<SplineBoundary<A>>,
+    ) -> Self {
+        assert_eq!(x.len(), y.len(), "x and y must be of the same length.");
+        assert!(x.len() >= 2, "At least 2 points are required.");
+        assert!(
+            x.windows(2).into_iter().all(|pair| pair[0] < pair[1]),
+            "x must be strictly increasing."
+        );
+
+        let n = x.len();
+        let two = A::from(2).unwrap();
+        let six = A::from(6).unwrap();
+
+        let h: Vec<A> = (0..n - 1).map(|i| x[i + 1] - x[i]).collect();
+        let slope: Vec<A> = (0..n - 1).map(|i| (y[i + 1] - y[i]) / h[i]).collect();
+
+        // Tridiagonal system for the second derivatives: `a` below, `b` on and `c`
+        // above the diagonal, with `d` on the right hand side.
+        let mut a = vec![A::zero(); n];
+        let mut b = vec![A::one(); n];
+        let mut c = vec![A::zero(); n];
+        let mut d = vec![A::zero(); n];
+
+        for i in 1..n - 1 {
+            a[i] = h[i - 1];
+            b[i] = two * (h[i - 1] + h[i]);
+            c[i] = h[i];
+            d[i] = six * (slope[i] - slope[i - 1]);
+        }
+
+        if let SplineBoundary::Clamped(start, end) = boundary.unwrap_or_default() {
+            b[0] = two * h[0];
+            c[0] = h[0];
+            d[0] = six * (slope[0] - start);
+
+            a[n - 1] = h[n - 2];
+            b[n - 1] = two * h[n - 2];
+            d[n - 1] = six * (end - slope[n - 2]);
+        }
+
+        // Thomas algorithm.
+        for i in 1..n {
+            let w = a[i] / b[i - 1];
+            b[i] = b[i] - w * c[i - 1];
+            d[i] = d[i] - w * d[i - 1];
+        }
+
+        let mut m = Array1::zeros(n);
+        m[n - 1] = d[n - 1] / b[n - 1];
+
+        for i in (0..n - 1).rev() {
+            m[i] = (d[i] - c[i] * m[i + 1]) / b[i];
+        }
+
+        return Self {
+            x: x.to_owned(),
+            y: y.to_owned(),
+            m,
+        };
+    }
+
+    /// Evaluate the spline at `x`.
+    pub fn evaluate_f64(&self, x: A) -> A {
+        if x.is_nan() {
+            return A::nan();
+        }
+
+        let i = interval_of(self.x.view(), x);
+        let h = self.x[i + 1] - self.x[i];
+
+        let lower = (self.x[i + 1] - x) / h;
+        let upper = A::one() - lower;
+
+        return lower * self.y[i]
+               + upper * self.y[i + 1]
+               + (
+                    (lower.powi(3) - lower) * self.m[i]
+                    + (upper.powi(3) - upper) * self.m[i + 1]
+               ) * h.powi(2) / A::from(6).unwrap();
+    }
+
+    /// Evaluate the spline at every element of `x`.
+    pub fn evaluate<S, D>(&self, x: &ArrayBase<S, D>) -> Array<A, D>
+    where   S: Data<Elem = A>,
+            D: Dimension,
+    {
+        return x.mapv(|x| self.evaluate_f64(x));
+    }
+}
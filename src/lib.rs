@@ -6,6 +6,13 @@ pub use primitives::{
     InitValue,
 };
 
+mod parallel;
+pub use parallel::{
+    DEFAULT_PARALLEL_THRESHOLD,
+    parallel_threshold,
+    set_parallel_threshold,
+};
+
 mod generic;
 pub use generic::{
     ArrayFromDuplicatedRows,
@@ -30,13 +37,142 @@ pub use boolarray::{
     BoolArrayViewMut,
     
     ArrayWithBoolIterMethods,
+    ArrayWithBoolLogicalMethods,
+    ArrayWithBoolLogicalInplaceMethods,
     ArrayWithBoolMaskMethods,
+    ArrayWithBoolMaskSelectMethods,
+    ArrayWithBoolMaskSelectAxisMethods,
+    ArrayWithBoolParallelMethods,
+    ArrayWithBoolParallelMaskMethods,
+    ArrayWithBoolSelectMethods,
+    BroadcastOperand,
 
     OptionBoolArray,
     OptionBoolArray1,
     OptionBoolArray2,
 };
 
+mod bitarray;
+pub use bitarray::{
+    BitArray,
+    BitArray1,
+    BitArray2,
+
+    ArrayWithBitIterMethods,
+    ArrayWithBitMaskMethods,
+};
+
+mod floatarray;
+pub use floatarray::{
+    DEFAULT_ATOL,
+    DEFAULT_RTOL,
+
+    ArrayWithFloatMethods,
+    ArrayWithFloatAtan2Methods,
+    ArrayWithFloatInplaceMethods,
+    ArrayWithFloatBinaryInplaceMethods,
+    ArrayWithFloatParallelMethods,
+    ArrayWithFloatPartialOrd,
+    ArrayWithFloatApproxEq,
+    ArrayWithFloatAngularMethods,
+    ArrayWithFloatLatLngMethods,
+    FloatBinaryOperand,
+};
+
+mod reductions;
+pub use reductions::{
+    ArrayWithFloatNanReductions,
+};
+
+mod statistics;
+pub use statistics::{
+    QuantileInterpolation,
+
+    ArrayWithFloatStatistics,
+};
+
+mod sorting;
+pub use sorting::{
+    RankMethod,
+
+    ArrayWithFloatSortMethods,
+};
+
+mod clipping;
+pub use clipping::{
+    ArrayWithFloatClipMethods,
+    ArrayWithFloatClipInplaceMethods,
+};
+
+mod cumulative;
+pub use cumulative::{
+    ArrayWithFloatCumulativeMethods,
+};
+
+mod differences;
+pub use differences::{
+    ArrayWithFloatDifferenceMethods,
+    ArrayWithFloatGradientMethods,
+};
+
+mod integration;
+pub use integration::{
+    ArrayWithFloatIntegrationMethods,
+    ArrayWithFloatSpacedIntegrationMethods,
+};
+
+mod rolling;
+pub use rolling::{
+    ArrayWithFloatRollingMethods,
+};
+
+mod convolution;
+pub use convolution::{
+    BorderMode,
+    ConvolveMode,
+
+    ArrayWithFloatConvolveMethods,
+    ArrayWithFloatConvolve2DMethods,
+};
+
+mod histogram;
+pub use histogram::{
+    HistogramBins,
+
+    ArrayWithFloatHistogramMethods,
+    ArrayWithFloatLatLngHistogramMethods,
+};
+
+mod interpolate;
+pub use interpolate::{
+    CubicSpline,
+    SplineBoundary,
+    interp,
+};
+
+mod f32array;
+pub use f32array::{
+    F32ArcArray,
+    F32ArcArray1,
+    F32ArcArray2,
+    F32Array,
+    F32Array1,
+    F32Array2,
+    F32ArrayView,
+    F32ArrayViewMut,
+    F32LatLng,
+    F32LatLngView,
+    F32LatLngViewMut,
+    F32LatLngArcArray,
+    F32LatLngArray,
+    F32LatLngArrayView,
+    F32LatLngArrayViewMut,
+
+    OptionF32Array,
+    OptionF32Array1,
+    OptionF32Array2,
+};
+
 mod f64array;
 pub use f64array::{
     F64ArcArray,
@@ -57,7 +193,26 @@ pub use f64array::{
     
     ArrayWithF64Methods,
     ArrayWithF64Atan2Methods,
+    ArrayWithF64InplaceMethods,
+    ArrayWithF64BinaryInplaceMethods,
+    ArrayWithF64ParallelMethods,
     ArrayWithF64PartialOrd,
+    ArrayWithF64ApproxEq,
+    ArrayWithF64NanReductions,
+    ArrayWithF64Statistics,
+    ArrayWithF64SortMethods,
+    ArrayWithF64ClipMethods,
+    ArrayWithF64ClipInplaceMethods,
+    ArrayWithF64CumulativeMethods,
+    ArrayWithF64DifferenceMethods,
+    ArrayWithF64GradientMethods,
+    ArrayWithF64IntegrationMethods,
+    ArrayWithF64SpacedIntegrationMethods,
+    ArrayWithF64RollingMethods,
+    ArrayWithF64ConvolveMethods,
+    ArrayWithF64Convolve2DMethods,
+    ArrayWithF64HistogramMethods,
+    ArrayWithF64LatLngHistogramMethods,
     ArrayWithF64MappedOperators,
     ArrayWithF64AngularMethods,
     ArrayWithF64LatLngMethods,
@@ -107,9 +262,15 @@ mod test_generic {
 
 #[cfg(test)]
 mod test_boolarray {
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+    use std::thread;
+
     use super::*;
+    use crate::parallel::with_parallel_threshold;
 
-    use ndarray::prelude::*;    
+    use ndarray::prelude::*;
+    use ndarray::Zip;    
     
     #[duplicate_item(
         ArrayType           TestName                BaseType        ReshapeFunction                     Answer;
@@ -153,16 +314,270 @@ mod test_boolarray {
         assert!(arr == answer);
 
         // Test Zip
-        // let arr2 = {
-        //     BaseType::from_iter(100_usize..200_usize)
-        //     .ReshapeFunction
-        // };
+        let arr2 = {
+            BaseType::from_iter(100_usize..200_usize)
+            .ReshapeFunction
+        };
+
+        mask.mask_apply_inplace(
+            Zip::from(&mut arr)
+                .and(&arr2),
+            &(|v:&mut usize, to_add: &usize| *v += to_add)
+        );
+
+        let answer = Zip::from(&answer)
+                         .and(&arr2)
+                         .and(&mask)
+                         .map_collect(|v, to_add, mask| if *mask { v + to_add } else { *v });
+        assert!(arr == answer);
+
+        // Test ArrayViewMut and closures by value
+        let mut arr3 = arr.clone();
+        mask.mask_apply_inplace(&mut arr3.view_mut(), |v: &mut usize| *v = 0);
+
+        let answer3 = Zip::from(&arr)
+                          .and(&mask)
+                          .map_collect(|v, mask| if *mask { 0 } else { *v });
+        assert!(arr3 == answer3);
+
+        // Test Zip of 5 producers
+        let mut count = 0;
+        mask.mask_apply_inplace(
+            Zip::from(&mut arr3)
+                .and(&arr)
+                .and(&arr2)
+                .and(&answer)
+                .and(&mask),
+            |v, a, b, c, m| {
+                *v = a + b + c + *m as usize;
+                count += 1;
+            }
+        );
+        assert!(count == mask.count());
+
+        let answer = Zip::from(&arr)
+                         .and(&arr2)
+                         .and(&answer)
+                         .and(&mask)
+                         .map_collect(|a, b, c, m| if *m { a + b + c + 1 } else { *a });
+        assert!(arr3 == answer);
+    }
+
+    #[test]
+    fn test_boolarray_parallel_methods() {
+        let arr = Array::from_shape_fn((200, 50), |(i, j)| (i * 50 + j) % 7 == 3);
+        let transposed = arr.t();
+        let mut values = Array::from_shape_fn((200, 50), |(i, j)| i * 50 + j);
+        let others = values.mapv(|v| v * 2);
+
+        // Force the parallel code path regardless of array size.
+        with_parallel_threshold(0, || {
+            assert_eq!(arr.par_any(), arr.any());
+            assert_eq!(arr.par_all(), arr.all());
+            assert!(arr.mapv(|_| true).par_all());
+            assert_eq!(arr.par_count(), arr.count());
+            assert_eq!(arr.par_indices(), arr.indices());
+            assert_eq!(transposed.par_indices(), transposed.indices());
+
+            let mut answer = values.clone();
+            arr.mask_apply_inplace(&mut answer, |v: &mut usize| *v *= 3);
+            arr.par_mask_apply_inplace(&mut values, |v: &mut usize| *v *= 3);
+            assert_eq!(values, answer);
+
+            arr.mask_apply_inplace(Zip::from(&mut answer).and(&others), |v: &mut usize, o: &usize| *v += o);
+            arr.par_mask_apply_inplace(Zip::from(&mut values).and(&others), |v: &mut usize, o: &usize| *v += o);
+            assert_eq!(values, answer);
+        });
+
+        // Record the threads `f` is called from: rayon's workers in parallel, and only
+        // the calling thread when falling back to the serial path.
+        let threads_of = | threshold: usize | {
+            let threads = Mutex::new(HashSet::new());
+
+            with_parallel_threshold(threshold, || {
+                arr.par_mask_apply_inplace(
+                    &mut values.clone(),
+                    |_: &mut usize| { threads.lock().unwrap().insert(thread::current().id()); }
+                );
+            });
+
+            threads.into_inner().unwrap()
+        };
+
+        assert!(!threads_of(0).contains(&thread::current().id()));
+        assert_eq!(threads_of(usize::MAX), HashSet::from([thread::current().id()]));
+    }
+
+    #[test]
+    fn test_bitarray() {
+        let arr = Array::from_shape_fn((7, 11, 13), |(i, j, k)| (i * 11 * 13 + j * 13 + k) % 5 == 2);
+        let bits = BitArray::from(&arr);
+
+        assert_eq!(bits.shape(), &[7, 11, 13]);
+        assert_eq!(bits.to_bool_array(), arr);
+        assert_eq!(BoolArray::from(&bits.clone()), arr);
+        assert_eq!(BitArray::from(&arr.t()).to_bool_array(), arr.t());
+
+        assert_eq!(bits.any(), arr.any());
+        assert_eq!(bits.all(), arr.all());
+        assert_eq!(bits.count(), arr.count());
+        assert_eq!(bits.indices(), arr.indices());
+
+        for axis in 0..3 {
+            assert_eq!(bits.any_axis(Axis(axis)), arr.any_axis(Axis(axis)));
+            assert_eq!(bits.all_axis(Axis(axis)), arr.all_axis(Axis(axis)));
+            assert_eq!(bits.count_axis(Axis(axis)), arr.count_axis(Axis(axis)));
+        }
+
+        let trues = BitArray1::from_elem(100, true);
+        assert!(trues.all());
+        assert_eq!(trues.count(), 100);
+        assert!(!BitArray2::from_elem((0, 3), true).any());
+
+        let mut bits = bits;
+        assert_eq!(bits.get((0, 0, 2)), Some(true));
+        assert_eq!(bits.get((7, 0, 0)), None);
+        bits.set((0, 0, 2), false);
+        bits.set((6, 10, 12), true);
+        assert_eq!(bits.get((0, 0, 2)), Some(false));
+        assert_eq!(bits.count(), arr.count());
+
+        let mut values = Array::from_shape_fn((7, 11, 13), |(i, j, k)| i * 11 * 13 + j * 13 + k);
+        let mut answer = values.clone();
+        let mask = bits.to_bool_array();
+        mask.mask_apply_inplace(&mut answer, |v: &mut usize| *v = 0);
+        bits.mask_apply_inplace(&mut values.view_mut(), |v: &mut usize| *v = 0);
+        assert_eq!(values, answer);
+
+        // Zips visit a transposed array in memory order, not logical order.
+        let others = values.mapv(|v| v + 1);
+        let mut transposed = values.t().to_owned();
+        let mut answer = transposed.clone();
+        mask.t().mask_apply_inplace(Zip::from(&mut answer).and(others.t()), |v: &mut usize, o: &usize| *v = *o);
+        BitArray::from(&mask.t())
+            .mask_apply_inplace(Zip::from(&mut transposed).and(others.t()), |v: &mut usize, o: &usize| *v = *o);
+        assert_eq!(transposed, answer);
+
+        // Non-contiguous views, whether given directly or through a Zip.
+        let mut strided = Array::from_shape_fn((14, 12, 13), |(i, j, k)| i * 12 * 13 + j * 13 + k);
+        let mut answer = strided.clone();
+        mask.mask_apply_inplace(&mut answer.slice_mut(s![..;2, 1.., ..]), |v: &mut usize| *v = 0);
+        bits.mask_apply_inplace(&mut strided.slice_mut(s![..;2, 1.., ..]), |v: &mut usize| *v = 0);
+        assert_eq!(strided, answer);
+
+        mask.t().mask_apply_inplace(Zip::from(answer.slice_mut(s![..;2, 1.., ..]).reversed_axes()), |v: &mut usize| *v += 1);
+        BitArray::from(&mask.t())
+            .mask_apply_inplace(Zip::from(strided.slice_mut(s![..;2, 1.., ..]).reversed_axes()), |v: &mut usize| *v += 1);
+        assert_eq!(strided, answer);
+
+        // Indices with the wrong number of dimensions are out of bounds.
+        let mut dyn_bits = BitArray::from(&arr2(&[[false, true, false], [true, false, true]]).into_dyn());
+        assert_eq!(dyn_bits.get(&[1, 0][..]), Some(true));
+        assert_eq!(dyn_bits.get(&[1][..]), None);
+        assert_eq!(dyn_bits.get(&[1, 0, 0][..]), None);
+        assert!(std::panic::catch_unwind(move || dyn_bits.set(&[1][..], true)).is_err());
+    }
+
+    #[test]
+    fn test_boolmask_select() {
+        let mut arr = arr2(&[
+            [1., 2., 3.],
+            [4., 5., 6.],
+        ]);
+        let mask = arr.gt(&3.5).or(&arr1(&[true, false, false]));
+
+        assert_eq!(mask.mask_select(&arr), arr1(&[1., 4., 5., 6.]));
+        assert_eq!(mask.view().mask_select(&arr.view()), arr1(&[1., 4., 5., 6.]));
+
+        mask.mask_assign(&mut arr, 0.);
+        assert_eq!(arr, arr2(&[[0., 2., 3.], [0., 0., 0.]]));
+
+        mask.mask_assign_from(&mut arr.view_mut(), &arr1(&[10., 40., 50., 60.]));
+        assert_eq!(arr, arr2(&[[10., 2., 3.], [40., 50., 60.]]));
 
-        // mask.mask_apply_inplace(
-        //     Zip::from(&mut arr)
-        //         .and(&arr2),
-        //     &(|v:&mut usize, to_add| *v += to_add)
-        // );
+        let latlng: F64LatLngArray = arr2(&[[10., 20.], [f64::NAN, 20.], [30., 40.]]);
+        let valid = latlng.mapv(|v| v.is_finite()).all_axis(Axis(1));
+        assert_eq!(valid.mask_select_axis(&latlng, Axis(0)), arr2(&[[10., 20.], [30., 40.]]));
+        assert_eq!(
+            arr1(&[false, true]).mask_select_axis(&latlng, Axis(1)),
+            arr2(&[[20.], [20.], [40.]])
+        );
+    }
+
+    #[test]
+    fn test_boolselect() {
+        let arr = arr2(&[
+            [1., -2., 3.],
+            [-4., 5., -6.],
+        ]);
+        let positive = arr.gt(&0.);
+
+        assert_eq!(positive.if_else(&arr, 0.), arr2(&[[1., 0., 3.], [0., 5., 0.]]));
+        assert_eq!(positive.if_else(1., -1.), arr2(&[[1., -1., 1.], [-1., 1., -1.]]));
+        assert_eq!(
+            positive.view().if_else(arr.view(), &arr1(&[10., 20., 30.])),
+            arr2(&[[1., 20., 3.], [10., 5., 30.]])
+        );
+        assert_eq!(
+            positive.if_else(&arr2(&[[100.], [200.]]), arr.mapv(f64::abs)),
+            arr2(&[[100., 2., 100.], [4., 200., 6.]])
+        );
+        assert_eq!(
+            arr1(&[true, false]).if_else(&arr1(&["a", "b"]), &arr1(&["c", "d"])),
+            arr1(&["a", "d"])
+        );
+    }
+
+    #[test]
+    fn test_boolaxis() {
+        let arr = arr2(&[
+            [true, true, false],
+            [false, false, false],
+            [true, true, true],
+        ]);
+
+        assert_eq!(arr.any_axis(Axis(1)), arr1(&[true, false, true]));
+        assert_eq!(arr.all_axis(Axis(1)), arr1(&[false, false, true]));
+        assert_eq!(arr.count_axis(Axis(1)), arr1(&[2, 0, 3]));
+        assert_eq!(arr.view().any_axis(Axis(0)), arr1(&[true, true, true]));
+        assert_eq!(arr.all_axis(Axis(0)), arr1(&[false, false, false]));
+        assert_eq!(arr.count_axis(Axis(0)), arr1(&[2, 2, 1]));
+
+        // Rows of a LatLng array containing any invalid coordinate.
+        let latlng: F64LatLngArray = arr2(&[[10., 20.], [f64::NAN, 20.], [10., f64::INFINITY]]);
+        assert_eq!(
+            latlng.mapv(|v| !v.is_finite()).any_axis(Axis(1)),
+            arr1(&[false, true, true])
+        );
+    }
+
+    #[test]
+    fn test_boollogical() {
+        let lhs = arr2(&[
+            [true, true, false, false],
+            [true, false, true, false],
+        ]);
+        let rhs = arr1(&[true, false, true, false]);
+
+        assert_eq!(lhs.and(&rhs), arr2(&[[true, false, false, false], [true, false, true, false]]));
+        assert_eq!(lhs.or(&rhs), arr2(&[[true, true, true, false], [true, false, true, false]]));
+        assert_eq!(lhs.xor(&rhs.view()), arr2(&[[false, true, true, false], [false, false, false, false]]));
+        assert_eq!(lhs.view().and_not(&rhs), arr2(&[[false, true, false, false], [false, false, false, false]]));
+        assert_eq!(rhs.not(), arr1(&[false, true, false, true]));
+
+        // Broadcasting against an (N, 1) column expands both sides.
+        let column = arr2(&[[true], [false]]);
+        assert_eq!(column.and(&rhs), arr2(&[[true, false, true, false], [false, false, false, false]]));
+
+        let mut inplace = lhs.clone();
+        inplace.and_inplace(&rhs);
+        assert_eq!(inplace, lhs.and(&rhs));
+        inplace.or_inplace(&arr2(&[[false], [true]]));
+        assert_eq!(inplace, arr2(&[[true, false, false, false], [true, true, true, true]]));
+        inplace.view_mut().xor_inplace(&rhs);
+        inplace.and_not_inplace(&arr1(&[false, false, false, true]));
+        inplace.not_inplace();
+        assert_eq!(inplace, arr2(&[[true, true, false, true], [true, false, true, true]]));
     }
 }
 
@@ -171,6 +586,7 @@ mod test_f64array {
     use std::cmp::Ordering;
     use std::f64::consts;
     use crate::f64array::ArrayWithF64MappedOperators;
+    use crate::parallel::with_parallel_threshold;
 
     use super::*;
     use ndarray::prelude::*;
@@ -298,9 +714,720 @@ mod test_f64array {
         );
         
     }
+
+    #[test]
+    fn test_f64array_binary_methods() {
+        let x = F64Array::from_shape_fn(
+            (3, 4),
+            |(i, j)| (i*4 + j) as f64
+        );
+        let y = arr2(&[[3.], [4.], [5.]]);
+
+        // Arrays are broadcast against each other.
+        let hypot = x.hypot(y.view());
+        assert_eq!(hypot.shape(), &[3, 4]);
+        assert_eq!(hypot[[0, 0]], 3.);
+        assert_eq!(hypot[[1, 3]], 8.06225774829855);
+        assert_eq!(hypot, x.hypot_arr(y.clone()));
+
+        assert_eq!(
+            x.slice(s![0, ..]).powf(arr1(&[0., 1., 2., 3.])),
+            arr1(&[1., 1., 4., 27.])
+        );
+        assert_eq!(x.powf(2.), x.powi(2));
+
+        assert_eq!(
+            x.slice(s![.., 0]).rem_euclid(arr1(&[3., 3., 5.]).view()),
+            arr1(&[0., 1., 3.])
+        );
+
+        assert_eq!(
+            x.slice(s![0, ..]).mul_add(arr1(&[2.]).view(), arr1(&[1., 0., 1., 0.]).view()),
+            arr1(&[1., 2., 5., 6.])
+        );
+    }
+
+    #[test]
+    fn test_f64array_inplace_methods() {
+        let x = F64Array::from_shape_fn(
+            (3, 4),
+            |(i, j)| (i*4 + j) as f64
+        );
+
+        let mut y = x.clone();
+        y.sqrt_inplace();
+        assert_eq!(y, x.sqrt());
+
+        y.powi_inplace(2);
+        y.hypot_inplace(arr2(&[[3.], [4.], [5.]]).view());
+        assert_eq!(y, x.sqrt().powi(2).hypot(arr2(&[[3.], [4.], [5.]])));
+
+        // Only the slice is changed.
+        let mut z = x.clone();
+        z.slice_mut(s![.., 0]).mul_add_inplace(2., 1.);
+        assert_eq!(z.column(0), arr1(&[1., 9., 17.]));
+        assert_eq!(z.slice(s![.., 1..]), x.slice(s![.., 1..]));
+
+        // A shared ArcArray is cloned before being mutated.
+        let arc = x.to_shared();
+        let mut arc_mut = arc.clone();
+        arc_mut.rem_euclid_inplace(3.);
+        assert_eq!(arc, x);
+        assert_eq!(arc_mut, x.rem_euclid(3.));
+    }
+
+    #[test]
+    fn test_f64array_parallel_methods() {
+        let x = F64Array::from_shape_fn(
+            (200, 50),
+            |(i, j)| (i*50 + j) as f64 / 100.
+        );
+        let y = F64Array1::linspace(-1., 1., 50).into_shape((1, 50)).unwrap();
+
+        // Force the parallel code path regardless of array size.
+        with_parallel_threshold(0, || {
+            assert_eq!(x.par_sin(), x.sin());
+            assert_eq!(x.par_powi(3), x.powi(3));
+            assert_eq!(x.par_hypot_f64(2.), x.hypot(2.));
+            assert_eq!(x.par_atan2_arrv(y.view()), x.atan2(y.view()));
+            assert_eq!(x.slice(s![..10, ..]).par_rem_euclid_f64(0.3), x.slice(s![..10, ..]).rem_euclid(0.3));
+        });
+    }
+
+    #[test]
+    fn test_f64array_partial_ord_nan() {
+        let x = arr1(&[1., f64::NAN, 3., 4.]);
+        let y = arr1(&[2., 2., f64::NAN, 4.]);
+
+        assert_eq!(
+            x.partial_cmp(&3.),
+            arr1(&[Some(Ordering::Less), None, Some(Ordering::Equal), Some(Ordering::Greater)])
+        );
+        assert_eq!(x.equal(&3.), arr1(&[false, false, true, false]));
+        assert_eq!(x.not_equal(&3.), arr1(&[true, true, false, true]));
+        assert_eq!(x.ge(&f64::NAN), arr1(&[false; 4]));
+
+        assert_eq!(
+            x.partial_cmp_array(&y),
+            arr1(&[Some(Ordering::Less), None, None, Some(Ordering::Equal)])
+        );
+        assert_eq!(x.lt_array(&y), arr1(&[true, false, false, false]));
+        assert_eq!(x.le_array(&y.view()), arr1(&[true, false, false, true]));
+        assert_eq!(x.gt_array(&y), arr1(&[false, false, false, false]));
+        assert_eq!(x.ge_array(&arr1(&[2.])), arr1(&[false, false, true, true]));
+        assert_eq!(x.equal_array(&y), arr1(&[false, false, false, true]));
+        assert_eq!(x.not_equal_array(&y), arr1(&[true, true, true, false]));
+    }
+
+    #[test]
+    fn test_f64array_partial_ord_broadcast() {
+        let latlngs: F64LatLngArray = arr2(&[
+            [51.5, -0.1],
+            [40.7, -74.0],
+            [-33.9, 151.2],
+            [35.7, 139.7],
+        ]);
+
+        // Compare every row against a single [lat, lng].
+        let north_east = latlngs.ge_array(&arr1(&[0., 0.]));
+        assert_eq!(
+            north_east,
+            arr2(&[[true, false], [true, false], [false, true], [true, true]])
+        );
+        assert_eq!(
+            north_east.indices(),
+            arr1(&[(0, 0), (1, 0), (2, 1), (3, 0), (3, 1)])
+        );
+
+        // Compare every column against a column vector of thresholds.
+        let mask = latlngs.lt_array(&arr2(&[[50.], [50.], [0.], [0.]]).view());
+        assert_eq!(mask.indices(), arr1(&[(0, 1), (1, 0), (1, 1), (2, 0)]));
+
+        let mut clipped = latlngs.clone();
+        mask.mask_apply_inplace(&mut clipped, &(|v: &mut f64| *v = 0.));
+        assert_eq!(
+            clipped,
+            arr2(&[[51.5, 0.], [0., 0.], [0., 151.2], [35.7, 139.7]])
+        );
+    }
+
+    #[test]
+    fn test_f64array_approx_eq() {
+        let rads = F64Array1::linspace(0., consts::PI, 7);
+        let sin_values = arr1(&[0., 0.5, 0.8660254, 1., 0.8660254, 0.5, 0.]);
+
+        assert!(rads.sin() != sin_values);
+        assert!(rads.sin().all_close(&sin_values, None, Some(1e-7), None));
+        assert!(!rads.sin().all_close(&sin_values, Some(0.), Some(0.), None));
+
+        let x = arr1(&[1., f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 100.]);
+        let y = arr1(&[1. + 1e-9, f64::NAN, f64::INFINITY, f64::INFINITY, 101.]);
+
+        assert_eq!(
+            x.is_close(&y, None, None, None),
+            arr1(&[true, false, true, false, false])
+        );
+        assert_eq!(
+            x.is_close(&y.view(), Some(0.01), None, Some(true)),
+            arr1(&[true, true, true, false, true])
+        );
+
+        // Broadcasting against a scalar-like array.
+        assert_eq!(
+            x.is_close(&arr1(&[1.]), None, None, None),
+            arr1(&[true, false, false, false, false])
+        );
+    }
+
+    #[test]
+    fn test_f64array_nan_reductions() {
+        let arr = arr2(&[
+            [1., f64::NAN, 3.],
+            [f64::NAN, f64::NAN, f64::NAN],
+            [4., 2., f64::NAN],
+        ]);
+
+        assert_eq!(arr.nansum(), 10.);
+        assert_eq!(arr.nanmean(), 2.5);
+        assert_eq!(arr.nanmin(), 1.);
+        assert_eq!(arr.nanmax(), 4.);
+        assert_eq!(arr.nanvar(0.), 1.25);
+        assert_eq!(arr.nanstd(1.), (5_f64/3.).sqrt());
+        assert_eq!(arr.nanargmin(), Some((0, 0)));
+        assert_eq!(arr.nanargmax(), Some((2, 0)));
+
+        let all_nan = Array1::<f64>::from_elem(3, f64::NAN);
+        assert_eq!(all_nan.nansum(), 0.);
+        assert!(all_nan.nanmean().is_nan());
+        assert!(all_nan.nanmax().is_nan());
+        assert!(all_nan.view().nanvar(0.).is_nan());
+        assert_eq!(all_nan.nanargmin(), None);
+
+        assert_eq!(arr.nansum_axis(Axis(1)), arr1(&[4., 0., 6.]));
+        assert_eq!(arr.nanmax_axis(Axis(0)), arr1(&[4., 2., 3.]));
+        assert!(arr.nanmean_axis(Axis(1))[1].is_nan());
+        assert_eq!(arr.nanstd_axis(Axis(1), 0.)[0], 1.);
+        assert_eq!(arr.nanargmin_axis(Axis(0)), arr1(&[Some(0), Some(2), Some(0)]));
+        assert_eq!(arr.nanargmax_axis(Axis(1)), arr1(&[Some(2), None, Some(0)]));
+    }
+
+    #[test]
+    fn test_f64array_statistics() {
+        let arr = arr1(&[3., 1., 4., 1., 5., 9., 2., 6.]);
+
+        assert_eq!(arr.median(None), 3.5);
+        assert_eq!(arr.quantile(0.25, None, None), 1.75);
+        assert_eq!(arr.quantile(0.25, Some(QuantileInterpolation::Lower), None), 1.);
+        assert_eq!(arr.quantile(0.25, Some(QuantileInterpolation::Higher), None), 2.);
+        assert_eq!(arr.quantile(0.25, Some(QuantileInterpolation::Nearest), None), 2.);
+        assert_eq!(arr.quantile(0.25, Some(QuantileInterpolation::Midpoint), None), 1.5);
+        assert_eq!(arr.percentile(100., None, None), 9.);
+        assert_eq!(arr.quantiles(&[0., 0.5, 1.], None, None), arr1(&[1., 3.5, 9.]));
+        assert_eq!(arr.mode(), 1.);
+
+        let with_nan = arr2(&[
+            [1., f64::NAN, 3.],
+            [4., 5., 6.],
+        ]);
+        assert!(with_nan.median(None).is_nan());
+        assert_eq!(with_nan.median(Some(true)), 4.);
+
+        let medians = with_nan.median_axis(Axis(1), None);
+        assert!(medians[0].is_nan());
+        assert_eq!(medians[1], 5.);
+        assert_eq!(with_nan.median_axis(Axis(1), Some(true)), arr1(&[2., 5.]));
+        assert_eq!(
+            with_nan.view().quantiles_axis(Axis(1), &[0., 1.], None, Some(true)),
+            arr2(&[[1., 3.], [4., 6.]])
+        );
+        assert_eq!(with_nan.mode_axis(Axis(0)), arr1(&[1., 5., 3.]));
+    }
+
+    #[test]
+    fn test_f64array_sort_methods() {
+        let arr = arr2(&[
+            [3., f64::NAN, 1.],
+            [2., 2., 5.],
+        ]);
+
+        let sorted = arr.sort_axis(Axis(1));
+        assert_eq!(sorted.slice(s![.., ..2]), arr2(&[[1., 3.], [2., 2.]]));
+        assert!(sorted[(0, 2)].is_nan());
+        assert_eq!(sorted[(1, 2)], 5.);
+
+        assert_eq!(arr.argsort_axis(Axis(1)), arr2(&[[2, 0, 1], [0, 1, 2]]));
+        assert_eq!(arr.argsort_axis(Axis(0)), arr2(&[[1, 1, 0], [0, 0, 1]]));
+        assert_eq!(
+            arr.argsort(),
+            arr1(&[(0, 2), (1, 0), (1, 1), (0, 0), (1, 2), (0, 1)])
+        );
+
+        let values = arr1(&[10., 20., 10., f64::NAN, 30., 20.]);
+        let nan_rank = |ranks: F64Array1, expected: &[f64]| {
+            assert!(ranks[3].is_nan());
+            assert_eq!(
+                ranks.iter().enumerate().filter(|(i, _)| *i != 3).map(|(_, r)| *r).collect::<Vec<f64>>(),
+                expected
+            );
+        };
+        nan_rank(values.rank(None), &[1.5, 3.5, 1.5, 5., 3.5]);
+        nan_rank(values.rank(Some(RankMethod::Min)), &[1., 3., 1., 5., 3.]);
+        nan_rank(values.rank(Some(RankMethod::Max)), &[2., 4., 2., 5., 4.]);
+        nan_rank(values.rank(Some(RankMethod::Dense)), &[1., 2., 1., 3., 2.]);
+        let ranks = arr.view().rank_axis(Axis(0), None);
+        assert!(ranks[(0, 1)].is_nan());
+        assert_eq!(ranks.column(0), arr1(&[2., 1.]));
+        assert_eq!(ranks.column(2), arr1(&[1., 2.]));
+        assert_eq!(ranks[(1, 1)], 1.);
+
+        let values = arr1(&[7., 1., 5., 3., 9., 2.]);
+        let partitioned = values.partition_axis(Axis(0), 2);
+        assert_eq!(partitioned[2], 3.);
+        assert!(partitioned.slice(s![..2]).iter().all(|v| *v < 3.));
+        assert!(partitioned.slice(s![3..]).iter().all(|v| *v > 3.));
+
+        let mut top = values.argpartition_axis(Axis(0), 3).slice(s![3..]).to_vec();
+        top.sort();
+        assert_eq!(top, vec![0, 2, 4]);
+
+        let mut bottom = arr.argpartition(1).slice(s![..2]).to_vec();
+        bottom.sort();
+        assert_eq!(bottom, vec![(0, 2), (1, 0)]);
+    }
+
+    #[test]
+    fn test_f64array_clip_methods() {
+        let arr = arr2(&[
+            [-95., 200.],
+            [f64::NAN, -10.],
+            [f64::INFINITY, f64::NEG_INFINITY],
+        ]);
+
+        let clamped = arr.clamp(-90., 90.);
+        assert_eq!(clamped.row(0), arr1(&[-90., 90.]));
+        assert!(clamped[(1, 0)].is_nan());
+        assert_eq!(clamped.row(2), arr1(&[90., -90.]));
+
+        let clipped = arr.clip(&arr1(&[-90., -180.]), &arr1(&[90., 180.]));
+        assert_eq!(clipped.row(0), arr1(&[-90., 180.]));
+        assert!(clipped[(1, 0)].is_nan());
+        assert_eq!(clipped[(1, 1)], -10.);
+        assert_eq!(clipped.row(2), arr1(&[90., -180.]));
+
+        let clipped = arr.clip(&arr2(&[[0.], [0.], [-1.]]), &arr2(&[[10.], [10.], [1.]]));
+        assert_eq!(clipped.row(0), arr1(&[0., 10.]));
+        assert_eq!(clipped.row(2), arr1(&[1., -1.]));
+
+        assert_eq!(
+            arr.nan_to_num(None, Some(1.), Some(-1.)),
+            arr2(&[[-95., 200.], [0., -10.], [1., -1.]])
+        );
+        assert_eq!(arr.nan_to_num(None, None, None)[(2, 0)], f64::MAX);
+        assert_eq!(arr.view().fill_nan(5.)[(1, 0)], 5.);
+
+        let mut inplace = arr.clone();
+        inplace.fill_nan_inplace(0.);
+        inplace.clip_inplace(&arr1(&[-90., -180.]), &arr1(&[90., 180.]));
+        assert_eq!(inplace, arr2(&[[-90., 180.], [0., -10.], [90., -180.]]));
+
+        let mut inplace = arr.clone();
+        inplace.view_mut().nan_to_num_inplace(Some(-1.), None, None);
+        inplace.clamp_inplace(-100., 100.);
+        assert_eq!(inplace, arr2(&[[-95., 100.], [-1., -10.], [100., -100.]]));
+    }
+
+    #[test]
+    fn test_f64array_cumulative_methods() {
+        let arr = arr2(&[
+            [1., 2.],
+            [3., f64::NAN],
+            [2., 4.],
+        ]);
+
+        let cumsum = arr.cumsum_axis(Axis(0));
+        assert_eq!(cumsum.column(0), arr1(&[1., 4., 6.]));
+        assert_eq!(cumsum[(0, 1)], 2.);
+        assert!(cumsum[(1, 1)].is_nan() && cumsum[(2, 1)].is_nan());
+
+        assert_eq!(arr.nancumsum_axis(Axis(0)), arr2(&[[1., 2.], [4., 2.], [6., 6.]]));
+        assert_eq!(arr.nancumprod_axis(Axis(0)), arr2(&[[1., 2.], [3., 2.], [6., 8.]]));
+        assert_eq!(arr.cumprod_axis(Axis(1)).column(0), arr1(&[1., 3., 2.]));
+        assert_eq!(arr.view().cummax_axis(Axis(0)).column(0), arr1(&[1., 3., 3.]));
+        assert_eq!(arr.cummin_axis(Axis(0)).column(0), arr1(&[1., 1., 1.]));
+        assert!(arr.cummax_axis(Axis(0))[(2, 1)].is_nan());
+
+        let leading_nan = arr1(&[f64::NAN, 3., 1., f64::NAN, 5.]);
+        let cummax = leading_nan.nancummax_axis(Axis(0));
+        assert!(cummax[0].is_nan());
+        assert_eq!(cummax.slice(s![1..]), arr1(&[3., 3., 3., 5.]));
+        assert_eq!(leading_nan.nancummin_axis(Axis(0)).slice(s![1..]), arr1(&[3., 1., 1., 1.]));
+    }
+
+    #[test]
+    fn test_f64array_difference_methods() {
+        let arr = arr2(&[
+            [1., 2., 4., 7., 0.],
+            [2., 3., 5., 8., 12.],
+        ]);
+
+        assert_eq!(arr.diff(0, Axis(1)), arr);
+        assert_eq!(arr.diff(1, Axis(1)), arr2(&[[1., 2., 3., -7.], [1., 2., 3., 4.]]));
+        assert_eq!(arr.diff(2, Axis(1)), arr2(&[[1., 1., -10.], [1., 1., 1.]]));
+        assert_eq!(arr.diff(1, Axis(0)), arr2(&[[1., 1., 1., 1., 12.]]));
+        assert_eq!(arr.diff(3, Axis(0)).shape(), &[0, 5]);
+
+        let f = arr1(&[1., 2., 4., 7., 11., 16.]);
+        assert_eq!(f.gradient(Axis(0), 1.), arr1(&[1., 1.5, 2.5, 3.5, 4.5, 5.]));
+        assert_eq!(f.gradient(Axis(0), 2.), arr1(&[0.5, 0.75, 1.25, 1.75, 2.25, 2.5]));
+
+        // numpy.gradient([1, 2, 4, 7, 11, 16], [0., 1., 1.5, 3.5, 4., 6.])
+        let coords = arr1(&[0., 1., 1.5, 3.5, 4., 6.]);
+        assert!(
+            f.gradient(Axis(0), coords.view()).all_close(
+                &arr1(&[1., 3., 3.5, 6.7, 6.9, 2.5]),
+                None, None, None,
+            )
+        );
+        assert_eq!(
+            arr.view().gradient(Axis(0), arr1(&[0., 2.])),
+            arr2(&[[0.5, 0.5, 0.5, 0.5, 6.]; 2])
+        );
+    }
+
+    #[test]
+    fn test_f64array_integration_methods() {
+        let x: F64Array1 = Array1::linspace(0., 2., 5);
+        let arr = ndarray::stack![Axis(0), x.mapv(|x| x * x), x.mapv(|x| x.powi(3))];
+
+        assert_eq!(arr.trapz_axis(Axis(1), 0.5), arr1(&[2.75, 4.25]));
+        assert!(
+            arr.simpson_axis(Axis(1), 0.5).all_close(&arr1(&[8./3., 4.]), None, None, None)
+        );
+        assert!(
+            arr.view().simpson_axis(Axis(1), x.view()).all_close(&arr1(&[8./3., 4.]), None, None, None)
+        );
+        assert_eq!(arr.trapz_axis(Axis(1), x.clone()), arr.trapz_axis(Axis(1), 0.5));
+
+        // Even number of intervals, non-uniform: exact for quadratics.
+        let x: F64Array1 = arr1(&[0., 0.5, 1.5, 2., 3.]);
+        let y = x.mapv(|x| x * x);
+        assert!((y.simpson_axis(Axis(0), x.view())[()] - 9_f64).abs() < 1e-12);
+
+        // Odd number of intervals, non-uniform: the last interval is integrated with
+        // Cartwright's correction, which is also exact for quadratics.
+        let quadratic = |x: f64| 2. * x * x - x + 1.;
+        let integral = |b: f64| 2. * b.powi(3) / 3. - b * b / 2. + b;
+        for x in [arr1(&[0., 0.5, 1.5, 2.]), arr1(&[0., 0.5, 1.5, 2., 3., 3.25])] {
+            let b = x[x.len() - 1];
+            let y = x.mapv(quadratic);
+            assert!((y.simpson_axis(Axis(0), x.view())[()] - integral(b)).abs() < 1e-12);
+        }
+
+        // Fewer than 3 points fall back to the trapezoidal rule.
+        assert_eq!(arr1(&[1., 3.]).simpson_axis(Axis(0), 2.)[()], 4.);
+
+        let cumulative = arr.cumtrapz_axis(Axis(1), 0.5, None);
+        assert_eq!(cumulative.shape(), &[2, 4]);
+        assert_eq!(cumulative.row(0), arr1(&[0.0625, 0.375, 1.1875, 2.75]));
+        assert_eq!(
+            arr.cumtrapz_axis(Axis(1), Array1::linspace(0., 2., 5), Some(0.)).row(0),
+            arr1(&[0., 0.0625, 0.375, 1.1875, 2.75])
+        );
+        assert_eq!(
+            arr.cumtrapz_axis(Axis(1), 0.5, Some(5.)).row(0),
+            arr1(&[5., 0.0625, 0.375, 1.1875, 2.75])
+        );
+    }
+
+    #[test]
+    fn test_f64array_rolling_methods() {
+        let arr = arr1(&[1., 2., 6., f64::NAN, 4., 3.]);
+
+        let assert_nan_eq = |result: F64Array1, expected: &[f64]| {
+            assert_eq!(result.len(), expected.len());
+            result.iter().zip(expected).for_each(
+                |(r, e)| assert!((r.is_nan() && e.is_nan()) || (r - e).abs() < 1e-12, "{} != {}", result, arr1(expected))
+            );
+        };
+        let nan = f64::NAN;
+
+        assert_nan_eq(arr.rolling_sum(Axis(0), 3, None, None), &[nan, nan, 9., nan, nan, nan]);
+        assert_nan_eq(arr.rolling_sum(Axis(0), 3, None, Some(2)), &[nan, 3., 9., 8., 10., 7.]);
+        assert_nan_eq(arr.rolling_mean(Axis(0), 3, None, Some(1)), &[1., 1.5, 3., 4., 5., 3.5]);
+        assert_nan_eq(arr.rolling_mean(Axis(0), 3, Some(true), Some(1)), &[1.5, 3., 4., 5., 3.5, 3.5]);
+        assert_nan_eq(arr.rolling_mean(Axis(0), 4, Some(true), Some(1)), &[1.5, 3., 3., 4., 13./3., 3.5]);
+        assert_nan_eq(arr.rolling_min(Axis(0), 3, None, Some(1)), &[1., 1., 1., 2., 4., 3.]);
+        assert_nan_eq(arr.rolling_max(Axis(0), 3, None, Some(1)), &[1., 2., 6., 6., 6., 4.]);
+        assert_nan_eq(arr.rolling_median(Axis(0), 3, None, Some(2)), &[nan, 1.5, 2., 4., 5., 3.5]);
+        assert_nan_eq(
+            arr.rolling_std(Axis(0), 3, None, Some(2), None),
+            &[nan, 0.5_f64.sqrt(), 7_f64.sqrt(), 8_f64.sqrt(), 2_f64.sqrt(), 0.5_f64.sqrt()]
+        );
+        assert_nan_eq(arr.rolling_std(Axis(0), 2, None, Some(1), Some(0.)), &[0., 0.5, 2., 0., 0., 0.5]);
+
+        let arr2d = arr2(&[[1., 10.], [2., 20.], [3., 30.]]);
+        assert_eq!(
+            arr2d.view().rolling_sum(Axis(0), 2, None, Some(1)),
+            arr2(&[[1., 10.], [3., 30.], [5., 50.]])
+        );
+        assert_eq!(
+            arr2d.rolling_max(Axis(1), 2, None, Some(1)),
+            arr2(&[[1., 10.], [2., 20.], [3., 30.]])
+        );
+    }
+
+    #[test]
+    fn test_f64array_convolve_methods() {
+        let signal = arr1(&[1., 2., 3.]);
+        let kernel = arr1(&[0., 1., 0.5]);
+
+        assert_eq!(signal.convolve(&kernel, None), arr1(&[0., 1., 2.5, 4., 1.5]));
+        assert_eq!(signal.convolve(&kernel, Some(ConvolveMode::Same)), arr1(&[1., 2.5, 4.]));
+        assert_eq!(signal.convolve(&kernel, Some(ConvolveMode::Valid)), arr1(&[2.5]));
+        assert_eq!(signal.view().convolve(&arr1(&[1., 1.]), Some(ConvolveMode::Same)), arr1(&[1., 3., 5.]));
+        assert_eq!(kernel.convolve(&signal, None), signal.convolve(&kernel, None));
+
+        assert_eq!(signal.correlate(&kernel, None), arr1(&[3.5]));
+        assert_eq!(signal.correlate(&kernel, Some(ConvolveMode::Full)), arr1(&[0.5, 2., 3.5, 3., 0.]));
+
+        let raster = arr2(&[
+            [1., 2., 3.],
+            [4., 5., 6.],
+        ]);
+        let kernel = arr2(&[[0., 1., 0.], [0., 0., 0.], [0., 0., 0.]]);
+
+        // Shifts values down by one row.
+        assert_eq!(
+            raster.correlate2d(&kernel, Some(BorderMode::Constant(0.))),
+            arr2(&[[0., 0., 0.], [1., 2., 3.]])
+        );
+        assert_eq!(raster.correlate2d(&kernel, None), arr2(&[[1., 2., 3.], [1., 2., 3.]]));
+        assert_eq!(
+            raster.correlate2d(&kernel, Some(BorderMode::Wrap)),
+            arr2(&[[4., 5., 6.], [1., 2., 3.]])
+        );
+        assert_eq!(
+            raster.view().convolve2d(&kernel, Some(BorderMode::Nearest)),
+            arr2(&[[4., 5., 6.], [4., 5., 6.]])
+        );
+
+        // Even kernels: correlate2d centres after the middle, convolve2d before it.
+        let kernel = arr2(&[[1., 1.]]);
+        assert_eq!(
+            raster.correlate2d(&kernel, Some(BorderMode::Constant(0.))),
+            arr2(&[[1., 3., 5.], [4., 9., 11.]])
+        );
+        assert_eq!(
+            raster.convolve2d(&kernel, Some(BorderMode::Constant(0.))),
+            arr2(&[[3., 5., 3.], [9., 11., 6.]])
+        );
+    }
+
+    #[test]
+    fn test_f64array_histogram_methods() {
+        let arr = arr2(&[
+            [0., 1., 1.5],
+            [2., f64::NAN, 4.],
+        ]);
+
+        let (counts, edges) = arr.histogram(HistogramBins::Count(4), None);
+        assert_eq!(counts, arr1(&[1, 2, 1, 1]));
+        assert_eq!(edges, arr1(&[0., 1., 2., 3., 4.]));
+
+        let (counts, _) = arr.histogram(HistogramBins::Count(2), Some((1., 3.)));
+        assert_eq!(counts, arr1(&[2, 1]));
+
+        let (counts, _) = arr.view().histogram(HistogramBins::Edges(arr1(&[0., 1.5, 10.])), None);
+        assert_eq!(counts, arr1(&[2, 3]));
+
+        let (density, edges) = arr.histogram_density(HistogramBins::Count(2), None);
+        assert_eq!(density, arr1(&[0.3, 0.2]));
+        assert_eq!((&density * (edges[1] - edges[0])).sum(), 1.);
+
+        let weights = arr2(&[[1., 2., 3.], [4., 5., 6.]]);
+        let (counts, _) = arr.histogram_weighted(HistogramBins::Count(2), None, weights.view(), None);
+        assert_eq!(counts, arr1(&[6., 10.]));
+        let (density, _) = arr.histogram_weighted(HistogramBins::Count(2), None, weights.view(), Some(true));
+        assert_eq!(density, arr1(&[0.1875, 0.3125]));
+
+        let edges = arr1(&[1., 2., 4.]);
+        assert_eq!(arr.digitize(&edges, None), arr2(&[[0, 1, 1], [2, 3, 3]]));
+        assert_eq!(arr.digitize(&edges.view(), Some(true)), arr2(&[[0, 0, 1], [1, 3, 2]]));
+
+        let points: F64LatLngArray = arr2(&[
+            [10., 100.],
+            [15., 100.],
+            [15., 160.],
+            [f64::NAN, 120.],
+            [30., 180.],
+        ]);
+        let (counts, lat_edges, lng_edges) = points.histogram2d(
+            (HistogramBins::Count(2), HistogramBins::Edges(arr1(&[100., 150., 200.]))),
+            None,
+            None,
+            None,
+        );
+        assert_eq!(counts, arr2(&[[2., 1.], [0., 1.]]));
+        assert_eq!(lat_edges, arr1(&[10., 20., 30.]));
+        assert_eq!(lng_edges, arr1(&[100., 150., 200.]));
+
+        let (density, _, _) = points.view().histogram2d(
+            (HistogramBins::Count(2), HistogramBins::Count(2)),
+            Some(((0., 40.), (100., 200.))),
+            Some(arr1(&[1., 1., 2., 5., 4.]).view()),
+            Some(true),
+        );
+        assert_eq!(density.sum() * 20. * 50., 1.);
+        assert_eq!(density[(0, 0)], 0.25 / 1000.);
+    }
+}
+
+
+#[cfg(test)]
+mod test_f64array_baseline_api {
+    // Only the imports needed before `ArrayWithF64Methods` became generic over
+    // `Float`, to check that all of its methods still resolve through it.
+    use crate::ArrayWithF64Methods;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_f64array_baseline_methods() {
+        // Within the domain of every method, as NaNs do not compare equal.
+        let x = arr2(&[[0.25, 0.5], [0.75, 0.125]]);
+        let y = arr2(&[[1., -2.], [0.5, 3.]]);
+
+        assert_eq!(x.abs(), x.mapv(f64::abs));
+        assert_eq!(x.abs_sub(2.), x.mapv(|v| (v - 2.).abs()));
+        assert_eq!(x.acos(), x.mapv(f64::acos));
+        assert_eq!((&x + 1.).acosh(), (&x + 1.).mapv(f64::acosh));
+        assert_eq!(x.asin(), x.mapv(f64::asin));
+        assert_eq!(x.asinh(), x.mapv(f64::asinh));
+        assert_eq!(x.atan(), x.mapv(f64::atan));
+        assert_eq!(x.atan2_f64(2.), x.mapv(|v| v.atan2(2.)));
+        assert_eq!(x.atan2_arr(y.clone()), x.atan2_arrv(y.view()));
+        assert_eq!(x.atan2_arrv(y.view()), ndarray::Zip::from(&x).and(&y).map_collect(|a, b| a.atan2(*b)));
+        assert_eq!(x.atanh(), x.mapv(f64::atanh));
+        assert_eq!(x.cbrt(), x.mapv(f64::cbrt));
+        assert_eq!(x.ceil(), x.mapv(f64::ceil));
+        assert_eq!(x.copysign(-1.), x.mapv(|v| v.copysign(-1.)));
+        assert_eq!(x.cos(), x.mapv(f64::cos));
+        assert_eq!(x.cosh(), x.mapv(f64::cosh));
+        assert_eq!(x.div_euclid(0.5), x.mapv(|v| v.div_euclid(0.5)));
+        assert_eq!(x.exp(), x.mapv(f64::exp));
+        assert_eq!(x.exp2(), x.mapv(f64::exp2));
+        assert_eq!(x.exp_m1(), x.mapv(f64::exp_m1));
+        assert_eq!(x.floor(), x.mapv(f64::floor));
+        assert_eq!(x.fract(), x.mapv(f64::fract));
+        assert_eq!(x.hypot(2.), x.mapv(|v| v.hypot(2.)));
+        assert_eq!(x.ln(), x.mapv(f64::ln));
+        assert_eq!(x.ln_1p(), x.mapv(f64::ln_1p));
+        assert_eq!(x.log(3.), x.mapv(|v| v.log(3.)));
+        assert_eq!(x.log10(), x.mapv(f64::log10));
+        assert_eq!(x.log2(), x.mapv(f64::log2));
+        assert_eq!(x.mul_add(2., 1.), x.mapv(|v| v.mul_add(2., 1.)));
+        assert_eq!(x.powf(1.5), x.mapv(|v| v.powf(1.5)));
+        assert_eq!(x.powi(3), x.mapv(|v| v.powi(3)));
+        assert_eq!(x.rem_euclid(0.5), x.mapv(|v| v.rem_euclid(0.5)));
+        assert_eq!(x.round(), x.mapv(f64::round));
+        assert_eq!(x.signum(), x.mapv(f64::signum));
+        assert_eq!(x.sin(), x.mapv(f64::sin));
+        assert_eq!(x.sin_cos(), x.mapv(f64::sin_cos));
+        assert_eq!(x.sinh(), x.mapv(f64::sinh));
+        assert_eq!(x.sqrt(), x.mapv(f64::sqrt));
+        assert_eq!(x.tan(), x.mapv(f64::tan));
+        assert_eq!(x.tanh(), x.mapv(f64::tanh));
+        assert_eq!(x.trunc(), x.mapv(f64::trunc));
+    }
+}
+
+#[cfg(test)]
+mod test_f32array {
+    use super::*;
+    use ndarray::prelude::*;
+    use ndarray::Zip;
+
+    #[test]
+    fn test_f32array_2d() {
+        let degs = F32Array::from_shape_fn(
+            (3, 4),
+            |(i, j)| (i*4 + j) as f32 * 30.
+        );
+
+        let sin_values: F32Array2 = degs.to_rad().sin();
+        let answer = degs.mapv(|v| v.to_radians().sin());
+        assert!(
+            Zip::from(&sin_values)
+                .and(&answer)
+                .all(|x, y| (x - y).abs() < 1e-6)
+        );
+
+        assert_eq!(
+            degs.slice(s![0, ..]).hypot(arr1(&[0_f32, 40., 80., 0.])),
+            arr1(&[0_f32, 50., 100., 90.])
+        );
+        assert_eq!(
+            degs.slice(s![0, ..]).rem_euclid(-45_f32),
+            arr1(&[0_f32, 30., 15., 0.])
+        );
+        assert_eq!(
+            degs.lt(&100.).indices(),
+            arr1(&[(0, 0), (0, 1), (0, 2), (0, 3)])
+        );
+
+        let mut latlngs: F32LatLngArray = arr2(&[[100., 170.], [-190., 0.]]);
+        latlngs.normalize();
+        assert_eq!(latlngs, arr2(&[[80., -10.], [10., 180.]]));
+    }
 }
 
 
+#[cfg(test)]
+mod test_interpolate {
+    use super::*;
+    use ndarray::prelude::*;
+
+    #[test]
+    fn test_interp() {
+        let xp = arr1(&[1., 2., 4.]);
+        let fp = arr1(&[10., 20., 0.]);
+
+        assert_eq!(
+            interp(&arr1(&[0., 1., 1.5, 3., 4., 5.]), xp.view(), fp.view(), None, None),
+            arr1(&[10., 10., 15., 10., 0., 0.])
+        );
+        assert_eq!(
+            interp(&arr2(&[[0., 2.5], [5., 2.]]), xp.view(), fp.view(), Some(-1.), Some(-2.)),
+            arr2(&[[-1., 15.], [-2., 20.]])
+        );
+        assert!(interp(&arr1(&[f64::NAN]), xp.view(), fp.view(), None, None)[0].is_nan());
+    }
+
+    #[test]
+    fn test_cubic_spline() {
+        let x: F64Array1 = arr1(&[0., 0.5, 2., 3., 4.5]);
+        let query = arr2(&[[-1., 0.25], [1., 2.5], [4., 6.]]);
+
+        // Natural splines reproduce straight lines.
+        let spline = CubicSpline::new(x.view(), x.mapv(|x| 2. * x + 1.).view(), None);
+        assert!(spline.evaluate(&query).all_close(&query.mapv(|x| 2. * x + 1.), None, None, None));
+
+        // Clamped splines with the exact end derivatives reproduce cubics.
+        let cubic = |x: f64| x.powi(3) - 2. * x;
+        let spline = CubicSpline::new(
+            x.view(),
+            x.mapv(cubic).view(),
+            Some(SplineBoundary::Clamped(-2., 3. * 4.5_f64.powi(2) - 2.)),
+        );
+        let inside = query.slice(s![..2, ..]);
+        assert!(spline.evaluate(&inside).all_close(&inside.mapv(cubic), None, None, None));
+        assert!((spline.evaluate_f64(2.) - cubic(2.)).abs() < 1e-12);
+
+        let natural = CubicSpline::new(x.view(), x.mapv(cubic).view(), Some(SplineBoundary::Natural));
+        assert!((natural.evaluate_f64(3.) - cubic(3.)).abs() < 1e-12);
+        assert!((natural.evaluate_f64(1.) - cubic(1.)).abs() > 1e-3);
+    }
+}
+
 #[cfg(test)]
 mod test_readme {
     use super::f64array::{
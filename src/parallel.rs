@@ -0,0 +1,57 @@
+#[cfg(test)]
+use std::cell::Cell;
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+
+/// Default number of elements below which the `par_` methods stay serial.
+pub const DEFAULT_PARALLEL_THRESHOLD: usize = 1 << 16;
+
+static PARALLEL_THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_PARALLEL_THRESHOLD);
+
+#[cfg(test)]
+thread_local! {
+    /// Threshold taking precedence over `PARALLEL_THRESHOLD` on the current thread, so
+    /// that tests can force either code path without racing each other over the global.
+    static LOCAL_THRESHOLD: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Number of elements below which the `par_` methods stay serial.
+///
+/// For small arrays, the overhead of distributing work across rayon's thread pool
+/// outweighs the gain; arrays with fewer elements than this are processed serially.
+pub fn parallel_threshold() -> usize {
+    return PARALLEL_THRESHOLD.load(Ordering::Relaxed);
+}
+
+/// Set the number of elements below which the `par_` methods stay serial.
+///
+/// This is a global setting; set it to `0` to always run in parallel, or to
+/// `usize::MAX` to never do so.
+pub fn set_parallel_threshold(threshold: usize) {
+    PARALLEL_THRESHOLD.store(threshold, Ordering::Relaxed);
+}
+
+/// Whether an array of `len` elements should be processed in parallel.
+pub(crate) fn use_parallel(len: usize) -> bool {
+    #[cfg(test)]
+    if let Some(threshold) = LOCAL_THRESHOLD.with(Cell::get) {
+        return len >= threshold;
+    }
+
+    return len >= parallel_threshold();
+}
+
+/// Run `func` with `threshold` in place of `parallel_threshold()`, on the current
+/// thread only.
+#[cfg(test)]
+pub(crate) fn with_parallel_threshold<R, F>(threshold: usize, func: F) -> R
+where   F: FnOnce() -> R,
+{
+    let previous = LOCAL_THRESHOLD.with(|local| local.replace(Some(threshold)));
+    let result = func();
+    LOCAL_THRESHOLD.with(|local| local.set(previous));
+
+    return result;
+}
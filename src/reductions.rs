@@ -0,0 +1,219 @@
+use duplicate::duplicate_item;
+use ndarray::{
+    ArcArray,
+    Array,
+    ArrayView,
+    ArrayViewMut,
+    Axis,
+    Dimension,
+    RemoveAxis,
+};
+use num_traits::Float;
+
+use super::floatarray::{
+    ArrayWithFloatMethods,
+};
+
+/// Sum of all non-NaN values; `0` if there are none.
+fn nansum<'a, A, I>(values: I) -> A
+where   A: Float + 'a,
+        I: Iterator<Item = &'a A>,
+{
+    return values.filter(|v| !v.is_nan())
+                 .fold(A::zero(), |sum, v| sum + *v);
+}
+
+/// Sum and count of all non-NaN values.
+fn nansum_count<'a, A, I>(values: I) -> (A, usize)
+where   A: Float + 'a,
+        I: Iterator<Item = &'a A>,
+{
+    return values.filter(|v| !v.is_nan())
+                 .fold((A::zero(), 0), |(sum, count), v| (sum + *v, count + 1));
+}
+
+/// Mean of all non-NaN values; NaN if there are none.
+fn nanmean<'a, A, I>(values: I) -> A
+where   A: Float + 'a,
+        I: Iterator<Item = &'a A>,
+{
+    let (sum, count) = nansum_count(values);
+
+    return match count {
+        0 => A::nan(),
+        count => sum / A::from(count).unwrap(),
+    };
+}
+
+/// Variance of all non-NaN values, with `ddof` delta degrees of freedom; NaN if there
+/// are no more than `ddof` of them.
+fn nanvar<'a, A, I>(values: I, ddof: A) -> A
+where   A: Float + 'a,
+        I: Iterator<Item = &'a A> + Clone,
+{
+    let (sum, count) = nansum_count(values.clone());
+    let count = A::from(count).unwrap();
+
+    if count <= ddof {
+        return A::nan();
+    }
+
+    let mean = sum / count;
+
+    return values.filter(|v| !v.is_nan())
+                 .fold(A::zero(), |sum, v| sum + (*v - mean).powi(2))
+           / (count - ddof);
+}
+
+/// Index and value of the first non-NaN value for which `cmp(value, current)` holds
+/// against every value before it; `None` if all values are NaN.
+fn nanarg_by<'a, A, P, I, F>(values: I, cmp: F) -> Option<(P, A)>
+where   A: Float + 'a,
+        I: Iterator<Item = (P, &'a A)>,
+        F: Fn(A, A) -> bool,
+{
+    return values.filter(|(_, v)| !v.is_nan())
+                 .fold(
+                    None,
+                    | found, (index, &value) | match found {
+                        Some((_, current)) if !cmp(value, current) => found,
+                        _ => Some((index, value)),
+                    }
+                 );
+}
+
+/// NaN-skipping reductions, for arrays using NaN as a missing-value marker.
+///
+/// Modelled on numpy's `nan*` functions: NaNs are ignored entirely, and each method
+/// behaves as if they were removed from the array. Where no values remain, `nansum`
+/// returns `0`, `nanargmin` and `nanargmax` return `None`, and all other methods
+/// return NaN.
+///
+/// The `_axis` variants reduce along `axis` only, returning an array of one fewer
+/// dimension.
+pub trait ArrayWithFloatNanReductions<D, A = f64> : ArrayWithFloatMethods<D, A>
+where   D: Dimension,
+        A: Float,
+{
+    type Pattern;
+
+    fn nansum(&self) -> A;
+    fn nanmean(&self) -> A;
+    fn nanmin(&self) -> A;
+    fn nanmax(&self) -> A;
+    fn nanvar(&self, ddof: A) -> A;
+    fn nanstd(&self, ddof: A) -> A;
+    fn nanargmin(&self) -> Option<Self::Pattern>;
+    fn nanargmax(&self) -> Option<Self::Pattern>;
+
+    #[duplicate_item(
+        __func_name__;
+        [ nansum_axis ];
+        [ nanmean_axis ];
+        [ nanmin_axis ];
+        [ nanmax_axis ];
+    )]
+    fn __func_name__(&self, axis: Axis) -> Array<A, D::Smaller>
+    where   D: RemoveAxis;
+
+    fn nanvar_axis(&self, axis: Axis, ddof: A) -> Array<A, D::Smaller>
+    where   D: RemoveAxis;
+    fn nanstd_axis(&self, axis: Axis, ddof: A) -> Array<A, D::Smaller>
+    where   D: RemoveAxis;
+
+    fn nanargmin_axis(&self, axis: Axis) -> Array<Option<usize>, D::Smaller>
+    where   D: RemoveAxis;
+    fn nanargmax_axis(&self, axis: Axis) -> Array<Option<usize>, D::Smaller>
+    where   D: RemoveAxis;
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatNanReductions<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    type Pattern = <D as Dimension>::Pattern;
+
+    fn nansum(&self) -> A {
+        return nansum(self.iter());
+    }
+    fn nanmean(&self) -> A {
+        return nanmean(self.iter());
+    }
+    fn nanmin(&self) -> A {
+        return self.iter().fold(A::nan(), |min, v| min.min(*v));
+    }
+    fn nanmax(&self) -> A {
+        return self.iter().fold(A::nan(), |max, v| max.max(*v));
+    }
+    fn nanvar(&self, ddof: A) -> A {
+        return nanvar(self.iter(), ddof);
+    }
+    fn nanstd(&self, ddof: A) -> A {
+        return self.nanvar(ddof).sqrt();
+    }
+    fn nanargmin(&self) -> Option<Self::Pattern> {
+        return nanarg_by(self.indexed_iter(), |value, min| value < min)
+               .map(|(index, _)| index);
+    }
+    fn nanargmax(&self) -> Option<Self::Pattern> {
+        return nanarg_by(self.indexed_iter(), |value, max| value > max)
+               .map(|(index, _)| index);
+    }
+
+    fn nansum_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.map_axis(axis, |lane| nansum(lane.iter()));
+    }
+    fn nanmean_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.map_axis(axis, |lane| nanmean(lane.iter()));
+    }
+    fn nanmin_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.map_axis(axis, |lane| lane.fold(A::nan(), |min, v| min.min(*v)));
+    }
+    fn nanmax_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.map_axis(axis, |lane| lane.fold(A::nan(), |max, v| max.max(*v)));
+    }
+    fn nanvar_axis(&self, axis: Axis, ddof: A) -> Array<A, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.map_axis(axis, |lane| nanvar(lane.iter(), ddof));
+    }
+    fn nanstd_axis(&self, axis: Axis, ddof: A) -> Array<A, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.map_axis(axis, |lane| nanvar(lane.iter(), ddof).sqrt());
+    }
+    fn nanargmin_axis(&self, axis: Axis) -> Array<Option<usize>, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.map_axis(
+            axis,
+            |lane| nanarg_by(lane.iter().enumerate(), |value, min| value < min)
+                   .map(|(index, _)| index)
+        );
+    }
+    fn nanargmax_axis(&self, axis: Axis) -> Array<Option<usize>, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.map_axis(
+            axis,
+            |lane| nanarg_by(lane.iter().enumerate(), |value, max| value > max)
+                   .map(|(index, _)| index)
+        );
+    }
+}
@@ -0,0 +1,314 @@
+use std::collections::VecDeque;
+
+use duplicate::duplicate_item;
+use ndarray::{
+    ArcArray,
+    Array,
+    ArrayView,
+    ArrayView1,
+    ArrayViewMut,
+    Axis,
+    Dimension,
+    Slice,
+};
+use num_traits::Float;
+
+use super::floatarray::{
+    ArrayWithFloatMethods,
+};
+use super::generic::{
+    map_lanes,
+};
+use super::primitives::{
+    InitValue,
+};
+use super::statistics::{
+    ArrayWithFloatStatistics,
+};
+
+/// Incrementally maintained state of a sliding window, fed with the non-NaN values
+/// entering and leaving it.
+trait RollingState<A> {
+    fn push(&mut self, index: usize, value: A);
+    fn pop(&mut self, index: usize, value: A);
+
+    /// Value of the statistic over the `count` non-NaN values currently in `window`.
+    fn value(&self, count: usize, window: ArrayView1<A>) -> A;
+}
+
+/// Running sum, and the mean if `mean` is set.
+struct SumState<A> {
+    sum: A,
+    mean: bool,
+}
+
+impl<A> RollingState<A> for SumState<A>
+where   A: Float + InitValue,
+{
+    fn push(&mut self, _: usize, value: A) {
+        self.sum = self.sum + value;
+    }
+    fn pop(&mut self, _: usize, value: A) {
+        self.sum = self.sum - value;
+    }
+    fn value(&self, count: usize, _: ArrayView1<A>) -> A {
+        return match (self.mean, count) {
+            (false, _) => self.sum,
+            (true, 0) => A::init_value(),
+            (true, count) => self.sum / A::from(count).unwrap(),
+        };
+    }
+}
+
+/// Running mean and sum of squared deviations, by Welford's algorithm.
+struct StdState<A> {
+    mean: A,
+    m2: A,
+    count: usize,
+    ddof: A,
+}
+
+impl<A> RollingState<A> for StdState<A>
+where   A: Float + InitValue,
+{
+    fn push(&mut self, _: usize, value: A) {
+        self.count += 1;
+
+        let delta = value - self.mean;
+        self.mean = self.mean + delta / A::from(self.count).unwrap();
+        self.m2 = self.m2 + delta * (value - self.mean);
+    }
+    fn pop(&mut self, _: usize, value: A) {
+        self.count -= 1;
+
+        if self.count == 0 {
+            self.mean = A::zero();
+            self.m2 = A::zero();
+        } else {
+            let delta = value - self.mean;
+            self.mean = self.mean - delta / A::from(self.count).unwrap();
+            self.m2 = self.m2 - delta * (value - self.mean);
+        }
+    }
+    fn value(&self, count: usize, _: ArrayView1<A>) -> A {
+        let count = A::from(count).unwrap();
+
+        return if count <= self.ddof {
+            A::init_value()
+        } else {
+            (self.m2.max(A::zero()) / (count - self.ddof)).sqrt()
+        };
+    }
+}
+
+/// Monotonic queue of the values in the window, whose front is the minimum, or the
+/// maximum if `max` is set.
+struct ExtremeState<A> {
+    queue: VecDeque<(usize, A)>,
+    max: bool,
+}
+
+impl<A> RollingState<A> for ExtremeState<A>
+where   A: Float + InitValue,
+{
+    fn push(&mut self, index: usize, value: A) {
+        while let Some((_, last)) = self.queue.back() {
+            if (self.max && *last <= value) || (!self.max && *last >= value) {
+                self.queue.pop_back();
+            } else {
+                break;
+            }
+        }
+
+        self.queue.push_back((index, value));
+    }
+    fn pop(&mut self, index: usize, _: A) {
+        if matches!(self.queue.front(), Some((front, _)) if *front == index) {
+            self.queue.pop_front();
+        }
+    }
+    fn value(&self, _: usize, _: ArrayView1<A>) -> A {
+        return self.queue.front()
+                         .map(|(_, value)| *value)
+                         .unwrap_or_else(A::init_value);
+    }
+}
+
+/// No incremental state; the median is computed from scratch for each window.
+struct MedianState;
+
+impl<A> RollingState<A> for MedianState
+where   A: Float + InitValue,
+{
+    fn push(&mut self, _: usize, _: A) {}
+    fn pop(&mut self, _: usize, _: A) {}
+    fn value(&self, _: usize, window: ArrayView1<A>) -> A {
+        return window.median(Some(true));
+    }
+}
+
+/// Slide a window of `window` elements along `lane`, returning the statistic of
+/// `state` for each position.
+fn rolling<A, R>(
+    lane: ArrayView1<A>,
+    window: usize,
+    center: bool,
+    min_periods: usize,
+    mut state: R,
+) -> Vec<A>
+where   A: Float + InitValue,
+        R: RollingState<A>,
+{
+    let len = lane.len();
+    let offset = if center { (window - 1) / 2 } else { 0 };
+
+    let mut start = 0;
+    let mut end = 0;
+    let mut count = 0;
+
+    return (0..len).map(
+        | index | {
+            let window_end = (index + offset + 1).min(len);
+            let window_start = (index + offset + 1).saturating_sub(window).min(window_end);
+
+            while end < window_end {
+                if !lane[end].is_nan() {
+                    state.push(end, lane[end]);
+                    count += 1;
+                }
+                end += 1;
+            }
+
+            while start < window_start {
+                if !lane[start].is_nan() {
+                    state.pop(start, lane[start]);
+                    count -= 1;
+                }
+                start += 1;
+            }
+
+            if count >= min_periods {
+                state.value(count, lane.slice_axis(Axis(0), Slice::from(start..end)))
+            } else {
+                A::init_value()
+            }
+        }
+    ).collect();
+}
+
+/// Rolling-window statistics along an axis, returning arrays of the same shape as
+/// `self`.
+///
+/// Each window spans `window` elements; by default it ends at the element it is
+/// computed for, or if `center` is set, it is centred on it instead, extending one
+/// element further back for even windows, as pandas does. Windows are clipped at the
+/// ends of the axis.
+///
+/// NaNs within a window are skipped. Where a window has fewer than `min_periods`
+/// non-NaN values, the result is NaN, which is also the `InitValue` for floats;
+/// `min_periods` defaults to `window`.
+///
+/// `rolling_sum`, `rolling_mean`, `rolling_std`, `rolling_min` and `rolling_max` take
+/// `O(n)` time along the axis, regardless of `window`.
+///
+/// Panics if `window` is `0` or `min_periods` is greater than `window`.
+pub trait ArrayWithFloatRollingMethods<D, A = f64> : ArrayWithFloatMethods<D, A>
+where   D: Dimension,
+        A: Float + InitValue,
+{
+    #[duplicate_item(
+        __func_name__;
+        [ rolling_sum ];
+        [ rolling_mean ];
+        [ rolling_min ];
+        [ rolling_max ];
+        [ rolling_median ];
+    )]
+    fn __func_name__(
+        &self,
+        axis: Axis,
+        window: usize,
+        center: Option<bool>,
+        min_periods: Option<usize>,
+    ) -> Array<A, D>;
+
+    /// Rolling standard deviation with `ddof` delta degrees of freedom, which
+    /// defaults to `1`.
+    fn rolling_std(
+        &self,
+        axis: Axis,
+        window: usize,
+        center: Option<bool>,
+        min_periods: Option<usize>,
+        ddof: Option<A>,
+    ) -> Array<A, D>;
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatRollingMethods<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float + InitValue,
+{
+    #[duplicate_item(
+        __func_name__           __state__;
+        [ rolling_sum ]         [ SumState { sum: A::zero(), mean: false } ];
+        [ rolling_mean ]        [ SumState { sum: A::zero(), mean: true } ];
+        [ rolling_min ]         [ ExtremeState { queue: VecDeque::new(), max: false } ];
+        [ rolling_max ]         [ ExtremeState { queue: VecDeque::new(), max: true } ];
+        [ rolling_median ]      [ MedianState ];
+    )]
+    fn __func_name__(
+        &self,
+        axis: Axis,
+        window: usize,
+        center: Option<bool>,
+        min_periods: Option<usize>,
+    ) -> Array<A, D> {
+        let center = center.unwrap_or(false);
+        let min_periods = min_periods.unwrap_or(window);
+
+        assert!(window > 0, "window must be at least 1.");
+        assert!(min_periods <= window, "min_periods must not be greater than window.");
+
+        return map_lanes(
+            self.view(),
+            axis,
+            |lane| rolling(lane, window, center, min_periods, __state__)
+        );
+    }
+
+    fn rolling_std(
+        &self,
+        axis: Axis,
+        window: usize,
+        center: Option<bool>,
+        min_periods: Option<usize>,
+        ddof: Option<A>,
+    ) -> Array<A, D> {
+        let center = center.unwrap_or(false);
+        let min_periods = min_periods.unwrap_or(window);
+        let ddof = ddof.unwrap_or_else(A::one);
+
+        assert!(window > 0, "window must be at least 1.");
+        assert!(min_periods <= window, "min_periods must not be greater than window.");
+
+        return map_lanes(
+            self.view(),
+            axis,
+            | lane | rolling(
+                lane,
+                window,
+                center,
+                min_periods,
+                StdState { mean: A::zero(), m2: A::zero(), count: 0, ddof }
+            )
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use std::cmp::Ordering;
+
+use duplicate::duplicate_item;
+use ndarray::{
+    ArcArray,
+    Array,
+    Array1,
+    ArrayView,
+    ArrayView1,
+    ArrayViewMut,
+    Axis,
+    Dimension,
+};
+use num_traits::Float;
+
+use super::floatarray::{
+    ArrayWithFloatMethods,
+};
+use super::generic::{
+    map_lanes,
+};
+
+/// How to rank tied values, following scipy's `rankdata`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RankMethod {
+    /// The average of the ranks the tied values would have been given.
+    Average,
+    /// The lowest of the ranks the tied values would have been given.
+    Min,
+    /// The highest of the ranks the tied values would have been given.
+    Max,
+    /// Like `Min`, but the next distinct value gets the next rank, leaving no gaps.
+    Dense,
+}
+
+impl Default for RankMethod {
+    fn default() -> Self {
+        return Self::Average;
+    }
+}
+
+/// Total ordering of floats, with all NaNs equal to each other and greater than any
+/// number.
+fn cmp_nan_last<A>(lhs: &A, rhs: &A) -> Ordering
+where   A: Float,
+{
+    return match (lhs.is_nan(), rhs.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => lhs.partial_cmp(rhs).unwrap(),
+    };
+}
+
+/// Indices that would stably sort `lane`.
+fn argsort_lane<A>(lane: ArrayView1<A>) -> Vec<usize>
+where   A: Float,
+{
+    let mut indices: Vec<usize> = (0..lane.len()).collect();
+    indices.sort_by(|lhs, rhs| cmp_nan_last(&lane[*lhs], &lane[*rhs]));
+
+    return indices;
+}
+
+/// Indices that would partition `lane` around its `kth` smallest element.
+fn argpartition_lane<A>(lane: ArrayView1<A>, kth: usize) -> Vec<usize>
+where   A: Float,
+{
+    assert!(kth < lane.len(), "kth out of bounds of the array.");
+
+    let mut indices: Vec<usize> = (0..lane.len()).collect();
+    indices.select_nth_unstable_by(kth, |lhs, rhs| cmp_nan_last(&lane[*lhs], &lane[*rhs]));
+
+    return indices;
+}
+
+/// 1-based ranks of the values in `lane`; NaNs are ranked NaN.
+fn rank_lane<A>(lane: ArrayView1<A>, method: RankMethod) -> Vec<A>
+where   A: Float,
+{
+    let order = argsort_lane(lane);
+    let mut ranks = vec![A::nan(); lane.len()];
+
+    let mut start = 0;
+    let mut dense = 0;
+
+    while start < order.len() && !lane[order[start]].is_nan() {
+        let value = lane[order[start]];
+        let end = start + order[start..].iter()
+                                        .take_while(|index| lane[**index] == value)
+                                        .count();
+        dense += 1;
+
+        let rank = match method {
+            RankMethod::Average => A::from(start + 1 + end).unwrap() / A::from(2).unwrap(),
+            RankMethod::Min => A::from(start + 1).unwrap(),
+            RankMethod::Max => A::from(end).unwrap(),
+            RankMethod::Dense => A::from(dense).unwrap(),
+        };
+
+        order[start..end].iter()
+                         .for_each(|index| ranks[*index] = rank);
+
+        start = end;
+    }
+
+    return ranks;
+}
+
+/// Sorting, ranking and partitioning, using a total ordering where NaNs are sorted
+/// after all numbers.
+///
+/// The `_axis` variants work on each lane along `axis` independently, and return
+/// arrays of the same shape, with each lane replaced by its sorted values, or
+/// the indices along `axis` that would sort it.
+///
+/// `argsort` and `argpartition` work on the whole array, returning an [`Array1`] of
+/// indices in the same form as [`ArrayWithBoolIterMethods::indices`].
+///
+/// [`ArrayWithBoolIterMethods::indices`]: crate::ArrayWithBoolIterMethods::indices
+pub trait ArrayWithFloatSortMethods<D, A = f64> : ArrayWithFloatMethods<D, A>
+where   D: Dimension,
+        A: Float,
+{
+    type Pattern;
+
+    /// Stable sort of each lane along `axis`.
+    fn sort_axis(&self, axis: Axis) -> Array<A, D>;
+    /// Indices along `axis` that would stably sort each lane.
+    fn argsort_axis(&self, axis: Axis) -> Array<usize, D>;
+    /// Indices of all elements, in the order that would stably sort the array.
+    fn argsort(&self) -> Array1<Self::Pattern>;
+
+    /// 1-based ranks of all elements among the whole array, ties resolved according to
+    /// `method`, which defaults to [`RankMethod::Average`]. NaNs are ranked NaN.
+    fn rank(&self, method: Option<RankMethod>) -> Array<A, D>;
+    /// 1-based ranks of each element within its lane along `axis`.
+    fn rank_axis(&self, axis: Axis, method: Option<RankMethod>) -> Array<A, D>;
+
+    /// Partially sort each lane along `axis`, so that the `kth` element is where it
+    /// would be if sorted, with no greater elements before it and no smaller elements
+    /// after it. The order within either side is unspecified.
+    ///
+    /// Panics if `kth` is not less than the length of `axis`.
+    fn partition_axis(&self, axis: Axis, kth: usize) -> Array<A, D>;
+    /// Indices along `axis` that would partition each lane as in `partition_axis`.
+    fn argpartition_axis(&self, axis: Axis, kth: usize) -> Array<usize, D>;
+    /// Indices of all elements, in an order that would partition the array around its
+    /// `kth` smallest element; the first `kth + 1` indices are those of the `kth + 1`
+    /// smallest elements.
+    ///
+    /// Panics if `kth` is not less than the number of elements.
+    fn argpartition(&self, kth: usize) -> Array1<Self::Pattern>;
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatSortMethods<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    type Pattern = <D as Dimension>::Pattern;
+
+    fn sort_axis(&self, axis: Axis) -> Array<A, D> {
+        return map_lanes(
+            self.view(),
+            axis,
+            | lane | {
+                let mut values = lane.to_vec();
+                values.sort_by(cmp_nan_last);
+                values
+            }
+        );
+    }
+    fn argsort_axis(&self, axis: Axis) -> Array<usize, D> {
+        return map_lanes(self.view(), axis, argsort_lane);
+    }
+    fn argsort(&self) -> Array1<Self::Pattern> {
+        let mut indexed: Vec<(Self::Pattern, A)> = self.indexed_iter()
+                                                       .map(|(index, value)| (index, *value))
+                                                       .collect();
+        indexed.sort_by(|(_, lhs), (_, rhs)| cmp_nan_last(lhs, rhs));
+
+        return indexed.into_iter()
+                      .map(|(index, _)| index)
+                      .collect();
+    }
+
+    fn rank(&self, method: Option<RankMethod>) -> Array<A, D> {
+        let values: Array1<A> = self.iter().cloned().collect();
+
+        return Array::from_shape_vec(
+            self.raw_dim(),
+            rank_lane(values.view(), method.unwrap_or_default())
+        ).unwrap();
+    }
+    fn rank_axis(&self, axis: Axis, method: Option<RankMethod>) -> Array<A, D> {
+        let method = method.unwrap_or_default();
+
+        return map_lanes(self.view(), axis, |lane| rank_lane(lane, method));
+    }
+
+    fn partition_axis(&self, axis: Axis, kth: usize) -> Array<A, D> {
+        return map_lanes(
+            self.view(),
+            axis,
+            | lane | {
+                argpartition_lane(lane, kth).into_iter()
+                                            .map(|index| lane[index])
+                                            .collect()
+            }
+        );
+    }
+    fn argpartition_axis(&self, axis: Axis, kth: usize) -> Array<usize, D> {
+        return map_lanes(self.view(), axis, |lane| argpartition_lane(lane, kth));
+    }
+    fn argpartition(&self, kth: usize) -> Array1<Self::Pattern> {
+        assert!(kth < self.len(), "kth out of bounds of the array.");
+
+        let mut indexed: Vec<(Self::Pattern, A)> = self.indexed_iter()
+                                                       .map(|(index, value)| (index, *value))
+                                                       .collect();
+        indexed.select_nth_unstable_by(kth, |(_, lhs), (_, rhs)| cmp_nan_last(lhs, rhs));
+
+        return indexed.into_iter()
+                      .map(|(index, _)| index)
+                      .collect();
+    }
+}
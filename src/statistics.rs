@@ -0,0 +1,336 @@
+use duplicate::duplicate_item;
+use ndarray::{
+    ArcArray,
+    Array,
+    Array1,
+    ArrayView,
+    ArrayViewMut,
+    Axis,
+    Dimension,
+    RemoveAxis,
+    Zip,
+};
+use num_traits::Float;
+
+use super::floatarray::{
+    ArrayWithFloatMethods,
+};
+
+/// How to pick a quantile lying between two data points `i < j`, following numpy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuantileInterpolation {
+    /// `i + (j - i) * fraction`.
+    Linear,
+    /// `i`.
+    Lower,
+    /// `j`.
+    Higher,
+    /// `i` or `j`, whichever is nearest; ties go to the even index.
+    Nearest,
+    /// `(i + j) / 2`.
+    Midpoint,
+}
+
+impl Default for QuantileInterpolation {
+    fn default() -> Self {
+        return Self::Linear;
+    }
+}
+
+/// Collect `values` into a sorted `Vec`, or `None` if there are NaNs and they are not
+/// to be skipped.
+fn sorted_values<'a, A, I>(values: I, skip_nan: bool) -> Option<Vec<A>>
+where   A: Float + 'a,
+        I: Iterator<Item = &'a A>,
+{
+    let mut sorted = Vec::new();
+
+    for value in values {
+        if value.is_nan() {
+            if skip_nan {
+                continue;
+            } else {
+                return None;
+            }
+        }
+
+        sorted.push(*value);
+    }
+
+    sorted.sort_by(|lhs, rhs| lhs.partial_cmp(rhs).unwrap());
+
+    return Some(sorted);
+}
+
+/// The `q`-th quantile of `sorted`, which must not contain NaNs.
+fn quantile_of_sorted<A>(
+    sorted: &[A],
+    q: A,
+    interpolation: QuantileInterpolation,
+) -> A
+where   A: Float,
+{
+    assert!(
+        q >= A::zero() && q <= A::one(),
+        "Quantile must be between 0 and 1."
+    );
+
+    if sorted.is_empty() {
+        return A::nan();
+    }
+
+    let position = q * A::from(sorted.len() - 1).unwrap();
+    let lower = position.floor();
+    let fraction = position - lower;
+    let lower = lower.to_usize().unwrap();
+    let higher = position.ceil().to_usize().unwrap();
+
+    return match interpolation {
+        QuantileInterpolation::Linear => sorted[lower] + (sorted[higher] - sorted[lower]) * fraction,
+        QuantileInterpolation::Lower => sorted[lower],
+        QuantileInterpolation::Higher => sorted[higher],
+        QuantileInterpolation::Nearest => {
+            let half = A::from(0.5).unwrap();
+
+            if fraction < half || (fraction == half && lower & 1 == 0) {
+                sorted[lower]
+            } else {
+                sorted[higher]
+            }
+        },
+        QuantileInterpolation::Midpoint => (sorted[lower] + sorted[higher]) / A::from(2).unwrap(),
+    };
+}
+
+/// The `qs`-th quantiles of `values`; all NaN if `values` contains NaNs and
+/// `skip_nan` is not set.
+fn quantiles<'a, A, I>(
+    values: I,
+    qs: &[A],
+    interpolation: QuantileInterpolation,
+    skip_nan: bool,
+) -> Vec<A>
+where   A: Float + 'a,
+        I: Iterator<Item = &'a A>,
+{
+    return match sorted_values(values, skip_nan) {
+        Some(sorted) => qs.iter()
+                          .map(|q| quantile_of_sorted(&sorted, *q, interpolation))
+                          .collect(),
+        None => vec![A::nan(); qs.len()],
+    };
+}
+
+/// The most frequent non-NaN value in `values`, the smallest one if there is a tie;
+/// NaN if there are none.
+fn mode<'a, A, I>(values: I) -> A
+where   A: Float + 'a,
+        I: Iterator<Item = &'a A>,
+{
+    let sorted = sorted_values(values, true).unwrap();
+
+    let mut found = (A::nan(), 0);
+    let mut index = 0;
+
+    while index < sorted.len() {
+        let count = sorted[index..].iter()
+                                   .take_while(|value| **value == sorted[index])
+                                   .count();
+
+        if count > found.1 {
+            found = (sorted[index], count);
+        }
+
+        index += count;
+    }
+
+    return found.0;
+}
+
+/// Descriptive statistics over a whole array, or along an axis.
+///
+/// Quantiles are interpolated according to `interpolation`, which defaults to
+/// [`QuantileInterpolation::Linear`]. If `skip_nan` is set, NaNs are ignored as if
+/// they were removed from the data; otherwise, which is the default, any NaN in the
+/// data turns the result into NaN. Data with no values yields NaN.
+///
+/// `q` must be within `[0, 1]` and `percentile`'s `p` within `[0, 100]`, or these
+/// methods panic.
+///
+/// The `_axis` variants compute the statistic for each lane along `axis`. For
+/// `quantiles_axis`, the quantiles replace the lanes along `axis`, so the result has
+/// length `qs.len()` along it.
+pub trait ArrayWithFloatStatistics<D, A = f64> : ArrayWithFloatMethods<D, A>
+where   D: Dimension,
+        A: Float,
+{
+    fn median(&self, skip_nan: Option<bool>) -> A;
+    fn quantile(
+        &self,
+        q: A,
+        interpolation: Option<QuantileInterpolation>,
+        skip_nan: Option<bool>,
+    ) -> A;
+    fn percentile(
+        &self,
+        p: A,
+        interpolation: Option<QuantileInterpolation>,
+        skip_nan: Option<bool>,
+    ) -> A;
+    fn quantiles(
+        &self,
+        qs: &[A],
+        interpolation: Option<QuantileInterpolation>,
+        skip_nan: Option<bool>,
+    ) -> Array1<A>;
+    /// The most frequent non-NaN value, the smallest one if there is a tie.
+    fn mode(&self) -> A;
+
+    fn median_axis(&self, axis: Axis, skip_nan: Option<bool>) -> Array<A, D::Smaller>
+    where   D: RemoveAxis;
+    fn quantile_axis(
+        &self,
+        axis: Axis,
+        q: A,
+        interpolation: Option<QuantileInterpolation>,
+        skip_nan: Option<bool>,
+    ) -> Array<A, D::Smaller>
+    where   D: RemoveAxis;
+    fn percentile_axis(
+        &self,
+        axis: Axis,
+        p: A,
+        interpolation: Option<QuantileInterpolation>,
+        skip_nan: Option<bool>,
+    ) -> Array<A, D::Smaller>
+    where   D: RemoveAxis;
+    fn quantiles_axis(
+        &self,
+        axis: Axis,
+        qs: &[A],
+        interpolation: Option<QuantileInterpolation>,
+        skip_nan: Option<bool>,
+    ) -> Array<A, D>
+    where   D: RemoveAxis;
+    fn mode_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where   D: RemoveAxis;
+}
+
+#[duplicate_item(
+    __array_type__                      __impl_generics__;
+    [ Array<A, D> ]                     [ D, A ];
+    [ ArcArray<A, D> ]                  [ D, A ];
+    [ ArrayView<'a, A, D> ]             [ 'a, D, A ];
+    [ ArrayViewMut<'a, A, D> ]          [ 'a, D, A ];
+)]
+impl<__impl_generics__> ArrayWithFloatStatistics<D, A>
+for __array_type__
+where   D: Dimension,
+        A: Float,
+{
+    fn median(&self, skip_nan: Option<bool>) -> A {
+        return self.quantile(A::from(0.5).unwrap(), None, skip_nan);
+    }
+    fn quantile(
+        &self,
+        q: A,
+        interpolation: Option<QuantileInterpolation>,
+        skip_nan: Option<bool>,
+    ) -> A {
+        return self.quantiles(&[q], interpolation, skip_nan)[0];
+    }
+    fn percentile(
+        &self,
+        p: A,
+        interpolation: Option<QuantileInterpolation>,
+        skip_nan: Option<bool>,
+    ) -> A {
+        return self.quantile(p / A::from(100).unwrap(), interpolation, skip_nan);
+    }
+    fn quantiles(
+        &self,
+        qs: &[A],
+        interpolation: Option<QuantileInterpolation>,
+        skip_nan: Option<bool>,
+    ) -> Array1<A> {
+        return Array1::from_vec(
+            quantiles(
+                self.iter(),
+                qs,
+                interpolation.unwrap_or_default(),
+                skip_nan.unwrap_or(false),
+            )
+        );
+    }
+    fn mode(&self) -> A {
+        return mode(self.iter());
+    }
+
+    fn median_axis(&self, axis: Axis, skip_nan: Option<bool>) -> Array<A, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.quantile_axis(axis, A::from(0.5).unwrap(), None, skip_nan);
+    }
+    fn quantile_axis(
+        &self,
+        axis: Axis,
+        q: A,
+        interpolation: Option<QuantileInterpolation>,
+        skip_nan: Option<bool>,
+    ) -> Array<A, D::Smaller>
+    where   D: RemoveAxis
+    {
+        let interpolation = interpolation.unwrap_or_default();
+        let skip_nan = skip_nan.unwrap_or(false);
+
+        return self.map_axis(
+            axis,
+            |lane| quantiles(lane.iter(), &[q], interpolation, skip_nan)[0]
+        );
+    }
+    fn percentile_axis(
+        &self,
+        axis: Axis,
+        p: A,
+        interpolation: Option<QuantileInterpolation>,
+        skip_nan: Option<bool>,
+    ) -> Array<A, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.quantile_axis(axis, p / A::from(100).unwrap(), interpolation, skip_nan);
+    }
+    fn quantiles_axis(
+        &self,
+        axis: Axis,
+        qs: &[A],
+        interpolation: Option<QuantileInterpolation>,
+        skip_nan: Option<bool>,
+    ) -> Array<A, D>
+    where   D: RemoveAxis
+    {
+        let interpolation = interpolation.unwrap_or_default();
+        let skip_nan = skip_nan.unwrap_or(false);
+
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = qs.len();
+
+        let mut result = Array::zeros(dim);
+
+        Zip::from(result.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(
+                | mut result_lane, lane | {
+                    result_lane.assign(
+                        &Array1::from_vec(quantiles(lane.iter(), qs, interpolation, skip_nan))
+                    )
+                }
+            );
+
+        return result;
+    }
+    fn mode_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where   D: RemoveAxis
+    {
+        return self.map_axis(axis, |lane| mode(lane.iter()));
+    }
+}